@@ -0,0 +1,128 @@
+//! Compares `graph::dijkstras`, which now searches an `IndexedGraph`, with the
+//! `BTreeSet` queue it used to walk the `Graph` map with directly.
+//!
+//! Run with `cargo bench --bench dijkstra`.
+
+use std::collections::{btree_map::Entry, BTreeMap, BTreeSet};
+
+use aoc24::{
+    bench::{time, Stats},
+    graph::{self, implicit::Predecessors, Graph, IndexedGraph},
+    grid::Direction,
+};
+
+const SIZE: isize = 140;
+const ITERATIONS: usize = 10;
+
+type Node = ((isize, isize), Direction);
+
+/// A maze-like graph of `(position, facing)` nodes, where stepping forward
+/// costs 1 and turning costs 1000. Every odd row is a wall with a gap in it
+/// every ninth column.
+fn build() -> Graph<Node> {
+    let open = |(row, col): (isize, isize)| {
+        (0..SIZE).contains(&row)
+            && (0..SIZE).contains(&col)
+            && (row % 2 == 0 || (col + row * 5) % 9 == 0)
+    };
+    let mut graph = Graph::new();
+    for row in 0..SIZE {
+        for col in 0..SIZE {
+            let pos = (row, col);
+            if !open(pos) {
+                continue;
+            }
+            for dir in Direction::all_directions() {
+                for turn in dir.adjacent() {
+                    graph::add_edge(&mut graph, (pos, dir), (pos, turn), 1000);
+                }
+                let ahead = dir.apply(pos);
+                if open(ahead) {
+                    graph::add_edge(&mut graph, (pos, dir), (ahead, dir), 1);
+                }
+            }
+        }
+    }
+    graph
+}
+
+/// The search as it was before the indexed graph.
+fn btree_dijkstras<'a, T: Ord>(graph: &'a Graph<T>, start: &'a T) -> BTreeMap<&'a T, usize> {
+    let mut visited = BTreeSet::new();
+    let mut queue = BTreeSet::new();
+    queue.insert((0usize, start));
+
+    let mut distances = BTreeMap::new();
+    distances.insert(start, 0usize);
+
+    while let Some((dist, cur)) = queue.pop_first() {
+        if !visited.insert(cur) {
+            continue;
+        }
+        for (next, weight) in graph.get(cur).into_iter().flatten() {
+            let new_dist = dist + *weight;
+            match distances.entry(next) {
+                Entry::Occupied(mut dist) if *dist.get() > new_dist => {
+                    dist.insert(new_dist);
+                    queue.insert((new_dist, next));
+                }
+                Entry::Occupied(_) => {}
+                Entry::Vacant(entry) => {
+                    entry.insert(new_dist);
+                    queue.insert((new_dist, next));
+                }
+            }
+        }
+    }
+
+    distances
+}
+
+fn report(name: &str, stats: Stats) {
+    println!(
+        "{:<24} {:>10} {:>10} {:>10}",
+        name,
+        format!("{:.1?}", stats.min),
+        format!("{:.1?}", stats.median),
+        format!("{:.1?}", stats.max)
+    );
+}
+
+fn main() -> anyhow::Result<()> {
+    let graph = build();
+    let start = ((0, 0), Direction::Right);
+    let expected = btree_dijkstras(&graph, &start);
+    println!(
+        "{} nodes, {} reachable, {} iterations",
+        graph.len(),
+        expected.len(),
+        ITERATIONS
+    );
+    anyhow::ensure!(
+        graph::dijkstras(&graph, &start) == expected,
+        "indexed search disagrees with the btree one"
+    );
+
+    println!(
+        "{:<24} {:>10} {:>10} {:>10}",
+        "search", "min", "median", "max"
+    );
+    report(
+        "btree queue",
+        time(ITERATIONS, || Ok(btree_dijkstras(&graph, &start)))?,
+    );
+    report(
+        "dijkstras (indexed)",
+        time(ITERATIONS, || Ok(graph::dijkstras(&graph, &start)))?,
+    );
+
+    // without the conversion, as when one index serves many searches
+    let mut indexed = IndexedGraph::from(&graph);
+    let id = indexed.intern(&start);
+    report(
+        "indexed, prebuilt",
+        time(ITERATIONS, || Ok(indexed.dijkstra(id, Predecessors::None)))?,
+    );
+
+    Ok(())
+}
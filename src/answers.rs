@@ -0,0 +1,66 @@
+use std::{fmt, path::Path};
+
+use anyhow::Context;
+use serde::Serialize;
+
+/// Expected answers for one input: the first line is part 1 and the second is
+/// part 2, with `-` standing in for a part whose answer isn't known.
+#[derive(Debug, Default)]
+pub struct Answers {
+    parts: [Option<String>; 2],
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Self {
+        let mut answers = Self::default();
+        for (slot, line) in answers.parts.iter_mut().zip(text.lines()) {
+            let line = line.trim();
+            if !line.is_empty() && line != "-" {
+                *slot = Some(line.to_string());
+            }
+        }
+        answers
+    }
+
+    pub fn read(filename: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let filename = filename.as_ref();
+        let text = std::fs::read_to_string(filename)
+            .with_context(|| format!("could not read {}", filename.display()))?;
+        Ok(Self::parse(&text))
+    }
+
+    pub fn part(&self, part: u8) -> Option<&str> {
+        self.parts
+            .get(usize::from(part).checked_sub(1)?)?
+            .as_deref()
+    }
+
+    /// Compares an answer to the one recorded for `part`.
+    pub fn verdict(&self, part: u8, answer: &str) -> Verdict {
+        match self.part(part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unrecorded,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unrecorded,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unrecorded => write!(f, "no recorded answer"),
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::{Duration, Instant};
+
+use crate::{report::catch_panic, solution::Day};
+
+/// Summary of repeated timings of a single stage.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            iterations: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Runs `f` `iterations` times and summarises how long each call took.
+pub fn time<T>(
+    iterations: usize,
+    mut f: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<Stats> {
+    anyhow::ensure!(iterations > 0, "need at least one iteration");
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let res = f()?;
+        samples.push(start.elapsed());
+        drop(res);
+    }
+    Ok(Stats::new(samples))
+}
+
+/// Timings of each stage of a day on one input.
+#[derive(Debug, Clone, Copy)]
+pub struct DayStats {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayStats {
+    pub fn stages(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+}
+
+/// Times parsing and both parts separately, each part reusing one parse. A
+/// stage that panics fails the day like one that returns an error.
+pub fn bench_day(day: &Day, input: &str, iterations: usize) -> anyhow::Result<DayStats> {
+    let parse = time(iterations, || catch_panic(|| day.parse(input)))?;
+    let parsed = catch_panic(|| day.parse(input))?;
+    let part1 = time(iterations, || catch_panic(|| day.part(1, parsed.as_ref())))?;
+    let part2 = time(iterations, || catch_panic(|| day.part(2, parsed.as_ref())))?;
+    Ok(DayStats {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Unparsable;
+
+    impl Solution for Unparsable {
+        type Input = u32;
+
+        fn parse(input: &str) -> anyhow::Result<u32> {
+            Ok(input.parse().expect("not a number"))
+        }
+
+        fn part1(n: &u32) -> anyhow::Result<impl fmt::Display> {
+            Ok(n + 1)
+        }
+
+        fn part2(n: &u32) -> anyhow::Result<impl fmt::Display> {
+            Ok(n * 2)
+        }
+    }
+
+    #[test]
+    fn bench_test() {
+        let day = Day::new::<Unparsable>(1);
+        let stats = bench_day(&day, "3", 3).unwrap();
+        assert!(stats.stages().iter().all(|(_, s)| s.iterations == 3));
+
+        let err = bench_day(&day, "x", 3).unwrap_err();
+        assert!(err.to_string().starts_with("panicked: not a number"));
+    }
+}
@@ -0,0 +1,330 @@
+use std::path::PathBuf;
+
+use aoc24::{
+    answers::{Answers, Verdict},
+    bench, days,
+    inputs::{Resolver, Slot},
+    log,
+    report::{self, Report},
+    scaffold, util,
+};
+use clap::Parser;
+
+#[derive(Debug, clap::Parser)]
+struct Args {
+    /// Log more of what days do while solving, repeat for more detail
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    /// Only log errors
+    #[arg(short, long, conflicts_with = "verbose", global = true)]
+    quiet: bool,
+
+    /// Directory holding the real inputs as dayNN.txt
+    #[arg(long, env = "AOC_INPUTS", default_value = "inputs", global = true)]
+    inputs: PathBuf,
+
+    /// Directory holding the real answers as dayNN.txt, one line per part
+    #[arg(long, env = "AOC_ANSWERS", default_value = "answers", global = true)]
+    answers: PathBuf,
+
+    #[clap(subcommand)]
+    cmd: Cmd,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum Cmd {
+    /// Run one part of a day, or every registered day with --all
+    Run {
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or - for stdin; defaults to the day's real input
+        file: Option<PathBuf>,
+        /// Use the numbered published example instead of the real input
+        #[arg(short, long, conflicts_with = "file")]
+        example: Option<u32>,
+        /// Run both parts of every day
+        #[arg(long, conflicts_with_all = ["day", "part", "file"])]
+        all: bool,
+        /// Compare against the recorded answers and fail on a mismatch
+        #[arg(long)]
+        check: bool,
+        /// Print answers as text, or as one JSON object per part with timings
+        /// and diagnostics
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Generate and register a new day along with an example fixture and test
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Time parsing and each part of one day, or of every day with an input
+    Bench {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Input file, or - for stdin; defaults to the day's real input
+        #[arg(requires = "day")]
+        file: Option<PathBuf>,
+        /// Use the numbered published example instead of the real input
+        #[arg(short, long, conflicts_with = "file")]
+        example: Option<u32>,
+        /// How many times to run each stage
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+        /// Print comma separated nanoseconds instead of a table
+        #[arg(long)]
+        csv: bool,
+    },
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+/// Prints a report, returning whether it counts as a failure. Text reports
+/// for a single run print the bare answer so they stay easy to pipe.
+fn print_report(report: &Report, format: Format, labelled: bool) -> anyhow::Result<bool> {
+    let failed = report.error.is_some() || matches!(report.check, Some(Verdict::Fail { .. }));
+    match format {
+        Format::Json => println!("{}", serde_json::to_string(report)?),
+        Format::Text => {
+            let label = if labelled {
+                format!("day {:02} part {}: ", report.day, report.part)
+            } else {
+                String::new()
+            };
+            if let Some(answer) = &report.answer {
+                match &report.check {
+                    Some(verdict) if labelled => println!("{}{} ({})", label, answer, verdict),
+                    Some(verdict) => {
+                        println!("{}", answer);
+                        eprintln!("{}", verdict);
+                    }
+                    None => println!("{}{}", label, answer),
+                }
+            }
+            if let (Some(error), true) = (&report.error, labelled) {
+                eprintln!("{}{}", label, error);
+            }
+        }
+    }
+    Ok(failed)
+}
+
+fn check_report(report: &mut Report, answers: &Answers) {
+    if let Some(answer) = &report.answer {
+        report.check = Some(answers.verdict(report.part, answer));
+    }
+}
+
+fn run_one(
+    resolver: &Resolver,
+    day: u8,
+    part: u8,
+    file: Option<PathBuf>,
+    slot: Slot,
+    check: bool,
+    format: Format,
+) -> anyhow::Result<()> {
+    let solution =
+        days::get(day).ok_or_else(|| anyhow::anyhow!("day {} is not solved yet", day))?;
+    let file = file.unwrap_or_else(|| resolver.input(day, slot));
+    let input = util::read_input(file)?;
+
+    let mut report = report::run(solution, &[part], &input, matches!(format, Format::Json))
+        .pop()
+        .expect("one report per part");
+    if check {
+        check_report(&mut report, &resolver.read_answers(day, slot)?);
+    }
+
+    if print_report(&report, format, false)? {
+        match &report.error {
+            Some(error) => anyhow::bail!("{}", error),
+            None => anyhow::bail!(
+                "day {} part {} does not match the recorded answer",
+                day,
+                part
+            ),
+        }
+    }
+    Ok(())
+}
+
+fn run_all(resolver: &Resolver, slot: Slot, check: bool, format: Format) -> anyhow::Result<()> {
+    let mut failures = 0;
+    for day in days::DAYS {
+        let answers = if check {
+            resolver.read_answers(day.day, slot)?
+        } else {
+            Answers::default()
+        };
+        let reports = match resolver.read_input(day.day, slot) {
+            Ok(input) => report::run(day, &[1, 2], &input, matches!(format, Format::Json)),
+            Err(e) => vec![
+                Report::failed(day.day, 1, &e),
+                Report::failed(day.day, 2, &e),
+            ],
+        };
+        for mut report in reports {
+            if check {
+                check_report(&mut report, &answers);
+            }
+            if print_report(&report, format, true)? {
+                failures += 1;
+            }
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{} parts failed", failures);
+    }
+    Ok(())
+}
+
+fn run_bench(
+    resolver: &Resolver,
+    day: Option<u8>,
+    file: Option<PathBuf>,
+    slot: Slot,
+    iterations: usize,
+    csv: bool,
+) -> anyhow::Result<()> {
+    let selected: Vec<_> = match day {
+        Some(day) => {
+            vec![days::get(day).ok_or_else(|| anyhow::anyhow!("day {} is not solved yet", day))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut results = Vec::new();
+    let mut failures = 0;
+    for day in selected {
+        let file = file
+            .clone()
+            .unwrap_or_else(|| resolver.input(day.day, slot));
+        let stats =
+            util::read_input(file).and_then(|input| bench::bench_day(day, &input, iterations));
+        match stats {
+            Ok(stats) => results.push((day.day, stats)),
+            Err(e) => {
+                eprintln!("day {:02}: {:#}", day.day, e);
+                failures += 1;
+            }
+        }
+    }
+
+    if csv {
+        println!("day,stage,iterations,min_ns,median_ns,max_ns");
+        for (day, stats) in results.iter() {
+            for (stage, s) in stats.stages() {
+                println!(
+                    "{},{},{},{},{},{}",
+                    day,
+                    stage,
+                    s.iterations,
+                    s.min.as_nanos(),
+                    s.median.as_nanos(),
+                    s.max.as_nanos()
+                );
+            }
+        }
+    } else {
+        println!(
+            "{:>3} {:<5} {:>12} {:>12} {:>12}",
+            "day", "stage", "min", "median", "max"
+        );
+        let mut total = std::time::Duration::ZERO;
+        for (day, stats) in results.iter() {
+            for (stage, s) in stats.stages() {
+                println!(
+                    "{:>3} {:<5} {:>12} {:>12} {:>12}",
+                    format!("{:02}", day),
+                    stage,
+                    format!("{:.1?}", s.min),
+                    format!("{:.1?}", s.median),
+                    format!("{:.1?}", s.max)
+                );
+                total += s.median;
+            }
+        }
+        println!("total of medians: {:.1?}", total);
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{} days failed", failures);
+    }
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let Args {
+        verbose,
+        quiet,
+        inputs,
+        answers,
+        cmd,
+    } = Args::parse();
+    log::set_max_level(if quiet {
+        log::Level::Error
+    } else {
+        log::level_from_verbosity(verbose)
+    });
+    let resolver = Resolver::new(inputs, answers);
+    match cmd {
+        Cmd::Run {
+            all: true,
+            example,
+            check,
+            format,
+            ..
+        } => run_all(&resolver, Slot::from_example(example), check, format),
+
+        Cmd::Run {
+            day: Some(day),
+            part: Some(part),
+            file,
+            example,
+            check,
+            format,
+            ..
+        } => run_one(
+            &resolver,
+            day,
+            part,
+            file,
+            Slot::from_example(example),
+            check,
+            format,
+        ),
+
+        Cmd::Run { .. } => unreachable!("clap requires day and part without --all"),
+
+        Cmd::New { day } => {
+            for path in scaffold::new_day(std::path::Path::new("."), day)? {
+                println!("wrote {}", path.display());
+            }
+            Ok(())
+        }
+
+        Cmd::Bench {
+            day,
+            file,
+            example,
+            iterations,
+            csv,
+        } => run_bench(
+            &resolver,
+            day,
+            file,
+            Slot::from_example(example),
+            iterations,
+            csv,
+        ),
+    }
+}
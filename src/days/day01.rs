@@ -0,0 +1,56 @@
+use std::{collections::HashMap, fmt};
+
+use crate::solution::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let (mut firsts, mut seconds) = (vec![], vec![]);
+        for line in input.lines() {
+            let mut parts = line.split_whitespace();
+
+            if let Some(first) = parts.next() {
+                if let Some(second) = parts.next() {
+                    let first = first.parse::<i32>()?;
+                    let second = second.parse::<i32>()?;
+
+                    firsts.push(first);
+                    seconds.push(second);
+                }
+            }
+        }
+
+        Ok((firsts, seconds))
+    }
+
+    fn part1((firsts, seconds): &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let (mut firsts, mut seconds) = (firsts.clone(), seconds.clone());
+        firsts.sort();
+        seconds.sort();
+
+        let sum: u32 = firsts
+            .iter()
+            .zip(seconds.iter())
+            .map(|(f, s)| f.abs_diff(*s))
+            .sum();
+        Ok(sum)
+    }
+
+    fn part2((firsts, seconds): &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let map = seconds.iter().cloned().fold(HashMap::new(), |mut acc, s| {
+            *acc.entry(s).or_insert(0) += 1;
+            acc
+        });
+
+        let sum: i32 = firsts
+            .iter()
+            .cloned()
+            .map(|f| f * map.get(&f).cloned().unwrap_or(0))
+            .sum();
+
+        Ok(sum)
+    }
+}
@@ -1,7 +1,6 @@
-use std::io::Read;
+use std::fmt;
 
-use aoc24::parser::take_uint;
-use clap::Parser;
+use crate::{parser::take_uint, solution::Solution};
 
 fn take_mul() -> impl Fn(&str) -> Option<(u64, &str)> {
     move |input: &str| {
@@ -38,14 +37,9 @@ fn take_dont() -> impl Fn(&str) -> Option<(bool, &str)> {
     }
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<usize> {
-    let mut file = std::fs::File::open(filename)?;
-
-    let mut input = String::new();
-    file.read_to_string(&mut input)?;
-
+fn sum_muls(input: &str) -> usize {
     let mut res = 0usize;
-    let mut cur = &input[..];
+    let mut cur = input;
     while let Some(pos) = cur.find("mul(") {
         match take_mul()(&cur[pos..]) {
             Some((mul, rest)) => {
@@ -59,16 +53,11 @@ fn parse_file(filename: &str) -> anyhow::Result<usize> {
         }
     }
 
-    Ok(res)
+    res
 }
 
-fn parse_file2(filename: &str) -> anyhow::Result<usize> {
-    let mut file = std::fs::File::open(filename)?;
-
-    let mut input = String::new();
-    file.read_to_string(&mut input)?;
-
-    let mut cur = &input[..];
+fn sum_enabled_muls(input: &str) -> usize {
+    let mut cur = input;
     let mut do_status = true;
     let mut res = 0usize;
     while !cur.is_empty() {
@@ -88,30 +77,23 @@ fn parse_file2(filename: &str) -> anyhow::Result<usize> {
         }
     }
 
-    Ok(res)
+    res
 }
 
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+pub struct Day03;
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let res = parse_file(&file)?;
-            println!("{}", res);
-            Ok(())
-        }
+impl Solution for Day03 {
+    type Input = String;
 
-        Args::Part2 { file } => {
-            let res = parse_file2(&file)?;
-            println!("{}", res);
-            Ok(())
-        }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        Ok(sum_muls(input))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        Ok(sum_enabled_muls(input))
     }
 }
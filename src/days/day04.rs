@@ -0,0 +1,96 @@
+use std::fmt;
+
+use crate::{
+    grid::{self, Direction8},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Char {
+    X,
+    M,
+    A,
+    S,
+    Other(char),
+}
+
+type Grid = grid::Grid<Char>;
+
+fn parse_input(input: &str) -> anyhow::Result<Grid> {
+    grid::parse_grid(input)?.map_result(|_, c| {
+        Ok(match c {
+            'X' => Char::X,
+            'M' => Char::M,
+            'A' => Char::A,
+            'S' => Char::S,
+            c => Char::Other(*c),
+        })
+    })
+}
+
+fn check(grid: &Grid, pos: grid::Index, dir: Direction8, expected: &[Char]) -> bool {
+    let mut cur = pos;
+
+    for c in expected {
+        if Some(c) != grid.get(cur) {
+            return false;
+        }
+        cur = dir.apply(cur);
+    }
+
+    true
+}
+
+fn check_all_part1(grid: &Grid, pos: grid::Index) -> usize {
+    Direction8::all()
+        .into_iter()
+        .filter(|dir| check(grid, pos, *dir, &[Char::X, Char::M, Char::A, Char::S]))
+        .count()
+}
+
+fn check_all_part2(grid: &Grid, pos: grid::Index) -> bool {
+    let Some(Char::A) = grid.get(pos) else {
+        return false;
+    };
+
+    Direction8::diagonals()
+        .into_iter()
+        .filter(|dir| {
+            let start = dir.invert().apply(pos);
+            check(grid, start, *dir, &[Char::M, Char::A, Char::S])
+        })
+        .count()
+        == 2
+}
+
+fn find_all(grid: &Grid, chr: Char) -> impl Iterator<Item = grid::Index> + '_ {
+    grid.iter_pos()
+        .filter(move |(_, c)| **c == chr)
+        .map(|(pos, _)| pos)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let sum = find_all(grid, Char::X)
+            .map(|pos| check_all_part1(grid, pos))
+            .sum::<usize>();
+
+        Ok(sum)
+    }
+
+    fn part2(grid: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let count = find_all(grid, Char::A)
+            .filter(|pos| check_all_part2(grid, *pos))
+            .count();
+
+        Ok(count)
+    }
+}
@@ -1,15 +1,9 @@
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
 
-use aoc24::{parser, util};
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::{parser, solution::Solution};
 
 fn take_ordering<'a>() -> impl Fn(&'a str) -> Option<((i64, i64), &'a str)> {
     parser::map(
@@ -27,7 +21,7 @@ fn take_input_line<'a>() -> impl Fn(&'a str) -> Option<(Vec<i64>, &'a str)> {
 }
 
 #[derive(Debug)]
-struct ParsedResult {
+pub struct ParsedResult {
     orderings: Vec<(i64, i64)>,
     inputs: Vec<Vec<i64>>,
 }
@@ -82,15 +76,6 @@ fn parse_input<S: AsRef<str>, I: Iterator<Item = S>>(lines: I) -> anyhow::Result
     Ok(ParsedResult { orderings, inputs })
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<ParsedResult> {
-    let mut lines = util::read_file_lines(filename)?;
-
-    let input = parse_input(&mut lines);
-
-    lines.error()?;
-    input
-}
-
 fn toposort(
     orderings: &HashMap<i64, HashSet<i64>>,
     node_subset: &HashSet<i64>,
@@ -169,47 +154,48 @@ impl ParsedResult {
     }
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let ords = output.orderings();
-
-            let sum = output
-                .iter_input(&ords)
-                .filter_map(|(valid, input)| {
-                    if valid {
-                        Some(input[input.len() / 2])
-                    } else {
-                        None
-                    }
-                })
-                .sum::<i64>();
-            println!("{}", sum);
-        }
+pub struct Day05;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
-            let ords = output.orderings();
-
-            let sum = output
-                .iter_input(&ords)
-                .filter_map(|(valid, input)| {
-                    if valid {
-                        return None;
-                    }
-                    let nodes = input.iter().copied().collect::<HashSet<_>>();
-                    let Ok(order) = toposort(&ords, &nodes) else {
-                        return None;
-                    };
-                    Some(order[order.len() / 2])
-                })
-                .sum::<i64>();
-
-            println!("{}", sum);
-        }
-    };
+impl Solution for Day05 {
+    type Input = ParsedResult;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input.lines())
+    }
 
-    Ok(())
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let ords = output.orderings();
+
+        let sum = output
+            .iter_input(&ords)
+            .filter_map(|(valid, input)| {
+                if valid {
+                    Some(input[input.len() / 2])
+                } else {
+                    None
+                }
+            })
+            .sum::<i64>();
+        Ok(sum)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let ords = output.orderings();
+
+        let sum = output
+            .iter_input(&ords)
+            .filter_map(|(valid, input)| {
+                if valid {
+                    return None;
+                }
+                let nodes = input.iter().copied().collect::<HashSet<_>>();
+                let Ok(order) = toposort(&ords, &nodes) else {
+                    return None;
+                };
+                Some(order[order.len() / 2])
+            })
+            .sum::<i64>();
+
+        Ok(sum)
+    }
 }
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use crate::{
+    grid::{self, Direction},
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Loc {
+    Space,
+    Hash,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    grid: grid::Grid<Loc>,
+    start: grid::Index,
+}
+
+impl TryFrom<char> for Loc {
+    type Error = anyhow::Error;
+
+    fn try_from(chr: char) -> anyhow::Result<Self> {
+        match chr {
+            '.' | '^' | '<' | '>' | 'v' => Ok(Loc::Space),
+            '#' => Ok(Loc::Hash),
+            chr => Err(anyhow::anyhow!("unexpected '{chr}'")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Grid> {
+    let (grid, markers) = grid::parse_typed(input, "^")?;
+    let start = markers.one('^')?;
+
+    Ok(Grid { grid, start })
+}
+
+fn fill_visited(output: &Grid) -> (grid::Grid<usize>, bool) {
+    let mut visited = output.grid.copy_default::<usize>();
+
+    let mut pos = output.start;
+    let mut state = Direction::Up;
+    let mut cycle = false;
+
+    while let Some((loc, visited)) = output.grid.get(pos).zip(visited.get_mut(pos)) {
+        // cycle detection
+        if (*visited & state as usize) != 0 {
+            cycle = true;
+            break;
+        }
+        *visited |= state as usize;
+        match loc {
+            Loc::Space => pos = state.apply(pos),
+            Loc::Hash => {
+                pos = state.apply_inverse(pos); // back out
+                state = state.rotate_90_right()
+            }
+        };
+    }
+
+    (visited, cycle)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let (visited, _) = fill_visited(output);
+
+        let positions = visited
+            .iter()
+            .zip(output.grid.iter())
+            .filter(|(v, x)| **x == Loc::Space && **v != 0)
+            .count();
+
+        Ok(positions)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut output = output.clone();
+        let (visited, _) = fill_visited(&output);
+
+        let positions = visited
+            .iter_pos()
+            .zip(output.grid.iter())
+            .filter(|((_, bits), x)| **x == Loc::Space && **bits != 0)
+            .map(|((pos, _), _)| pos)
+            .collect::<Vec<_>>();
+
+        let mut cycles = 0;
+        for pos in positions {
+            let Some(loc) = output.grid.get_mut(pos) else {
+                Err(anyhow::anyhow!("could not get location"))?
+            };
+            let prev = *loc;
+            *loc = Loc::Hash;
+
+            let (_, cycle) = fill_visited(&output);
+            cycles += cycle as usize;
+
+            let Some(loc) = output.grid.get_mut(pos) else {
+                Err(anyhow::anyhow!("could not get location"))?
+            };
+            *loc = prev;
+        }
+
+        Ok(cycles)
+    }
+}
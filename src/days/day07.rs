@@ -1,15 +1,6 @@
-use std::io::BufRead;
+use std::fmt;
 
-use aoc24::parser;
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::{parser, solution::Solution};
 
 fn concat(lhs: u64, rhs: u64) -> u64 {
     // shift lhs to the right by the number of digits in rhs
@@ -41,7 +32,7 @@ fn solve2(lhs: u64, cur: u64, rhs: &[u64]) -> bool {
     }
 }
 
-struct Line {
+pub struct Line {
     lhs: u64,
     rhs: Vec<u64>,
 }
@@ -74,46 +65,39 @@ fn take_line<'a>() -> impl Fn(&'a str) -> Option<(Line, &'a str)> {
     }
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<Vec<Line>> {
-    let file = std::fs::File::open(filename)?;
-    let bufreader = std::io::BufReader::new(file);
-    let lines = bufreader
-        .lines()
-        .map(|line| {
-            let line = line.ok()?;
-            let take_line = take_line();
-            Some(take_line(&line)?.0)
-        })
-        .collect::<Option<Vec<_>>>()
-        .ok_or(anyhow::anyhow!("could not parse file"))?;
-
-    Ok(lines)
-}
+pub struct Day07;
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let valids = output
-                .iter()
-                .filter(|l| l.solve())
-                .map(|l| l.lhs)
-                .sum::<u64>();
-            println!("{}", valids);
-            Ok(())
-        }
+impl Solution for Day07 {
+    type Input = Vec<Line>;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let lines = input
+            .lines()
+            .map(|line| {
+                let take_line = take_line();
+                Some(take_line(line)?.0)
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or(anyhow::anyhow!("could not parse file"))?;
 
-            let valids = output
-                .iter()
-                .filter(|l| l.solve2())
-                .map(|l| l.lhs)
-                .sum::<u64>();
-            println!("{}", valids);
-            Ok(())
-        }
+        Ok(lines)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let valids = output
+            .iter()
+            .filter(|l| l.solve())
+            .map(|l| l.lhs)
+            .sum::<u64>();
+        Ok(valids)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let valids = output
+            .iter()
+            .filter(|l| l.solve2())
+            .map(|l| l.lhs)
+            .sum::<u64>();
+        Ok(valids)
     }
 }
@@ -0,0 +1,109 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::{
+    grid::{self, Grid},
+    point::Point,
+    solution::Solution,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Loc {
+    Space,
+    Antenna(char),
+}
+
+impl TryFrom<char> for Loc {
+    type Error = anyhow::Error;
+
+    fn try_from(chr: char) -> anyhow::Result<Self> {
+        match chr {
+            '.' => Ok(Loc::Space),
+            chr if chr.is_ascii_alphanumeric() => Ok(Loc::Antenna(chr)),
+            chr => Err(anyhow::anyhow!("unexpected '{chr}'")),
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid<Loc>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let (grid, _) = grid::parse_typed(input, "")?;
+        Ok(grid)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut antinodes = output.copy_default();
+        let positions = output
+            .iter_pos()
+            .map(|((row, col), loc)| (loc, (row, col)))
+            .filter(|(loc, _)| matches!(loc, Loc::Antenna(_)))
+            .fold(
+                HashMap::new(),
+                |mut acc: HashMap<Loc, HashSet<(isize, isize)>>, (loc, pos)| {
+                    acc.entry(*loc).or_default().insert(pos);
+                    acc
+                },
+            );
+
+        for (_, points) in positions.iter() {
+            for p1 in points.iter() {
+                for p2 in points.iter() {
+                    if p1 == p2 {
+                        continue;
+                    }
+
+                    let delta = Point::from(*p2) - Point::from(*p1);
+                    let p3 = Point::from(*p1) + delta * 2;
+                    if let Some(loc) = antinodes.get_mut(p3.into()) {
+                        *loc = true;
+                    }
+                }
+            }
+        }
+
+        let count = antinodes.iter().filter(|v| **v).count();
+        Ok(count)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut antinodes = output.copy_default();
+        let positions = output
+            .iter_pos()
+            .map(|((row, col), loc)| (loc, (row, col)))
+            .filter(|(loc, _)| matches!(loc, Loc::Antenna(_)))
+            .fold(
+                HashMap::new(),
+                |mut acc: HashMap<Loc, HashSet<(isize, isize)>>, (loc, pos)| {
+                    acc.entry(*loc).or_default().insert(pos);
+                    acc
+                },
+            );
+
+        for (_, points) in positions.iter() {
+            for p1 in points.iter() {
+                for p2 in points.iter() {
+                    if p1 == p2 {
+                        continue;
+                    }
+
+                    let delta = (Point::from(*p2) - Point::from(*p1)).reduce();
+
+                    let mut p3 = Point::from(*p1);
+                    while let Some(loc) = antinodes.get_mut(p3.into()) {
+                        *loc = true;
+                        p3 += delta;
+                    }
+                }
+            }
+        }
+
+        let count = antinodes.iter().filter(|v| **v).count();
+        Ok(count)
+    }
+}
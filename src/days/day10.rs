@@ -0,0 +1,133 @@
+use std::{collections::HashSet, fmt, rc::Rc};
+
+use crate::{grid, solution::Solution, trace};
+
+fn walk2(
+    grid: &grid::Grid<u32>,
+    visited: &mut grid::Grid<Option<usize>>,
+    pos: (isize, isize),
+    value: u32,
+) -> usize {
+    match visited.get(pos) {
+        Some(Some(v)) => {
+            return *v;
+        }
+        None => return 0,
+        _ => {}
+    };
+
+    if value == 9 {
+        return 1;
+    }
+
+    let mut res = 0;
+    if value == 9 {
+        res = 1;
+    } else {
+        for dir in grid::Direction::all_directions() {
+            let pos2 = dir.apply(pos);
+
+            let Some(v) = grid.get(pos2) else {
+                continue;
+            };
+            if *v != value + 1 {
+                continue;
+            }
+
+            res += walk2(grid, visited, pos2, value + 1);
+        }
+    }
+
+    if let Some(v) = visited.get_mut(pos) {
+        *v = Some(res);
+    };
+
+    res
+}
+
+type TrailEnds = Rc<HashSet<(isize, isize)>>;
+
+fn walk(
+    grid: &grid::Grid<u32>,
+    visited: &mut grid::Grid<Option<TrailEnds>>,
+    pos: (isize, isize),
+    value: u32,
+) -> TrailEnds {
+    match visited.get(pos) {
+        Some(Some(v)) => {
+            return v.clone();
+        }
+        None => return Rc::new(HashSet::new()),
+        _ => {}
+    };
+
+    let mut res;
+    if value == 9 {
+        res = HashSet::from([(pos)]);
+    } else {
+        res = HashSet::new();
+        for dir in grid::Direction::all_directions() {
+            let pos2 = dir.apply(pos);
+
+            let Some(v) = grid.get(pos2) else {
+                continue;
+            };
+            if *v != value + 1 {
+                continue;
+            }
+
+            res.extend(walk(grid, visited, pos2, value + 1).iter().cloned());
+        }
+    }
+
+    let res = Rc::new(res);
+    if let Some(v) = visited.get_mut(pos) {
+        *v = Some(res.clone());
+    };
+
+    res
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = grid::Grid<u32>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        let output = grid::parse_grid(input)?;
+        output.map_result(|_, chr| {
+            chr.to_digit(10)
+                .ok_or_else(|| anyhow::anyhow!("not a digit"))
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut visited = grid.copy_default();
+        let zeros = grid.iter_pos().filter(|(_, c)| **c == 0);
+
+        let mut res = 0;
+        for ((row, col), c) in zeros {
+            // print the row
+            let score = walk(grid, &mut visited, (row, col), *c).len();
+            trace!("row {}, col {}, score {}", row, col, score);
+            res += score;
+        }
+
+        Ok(res)
+    }
+
+    fn part2(grid: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut visited = grid.copy_default();
+        let zeros = grid.iter_pos().filter(|(_, c)| **c == 0);
+
+        let mut res = 0;
+        for ((row, col), c) in zeros {
+            // print the row
+            let score = walk2(grid, &mut visited, (row, col), *c);
+            trace!("row {}, col {}, score {}", row, col, score);
+            res += score;
+        }
+
+        Ok(res)
+    }
+}
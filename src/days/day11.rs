@@ -1,20 +1,10 @@
-use clap::Parser;
 use either::Either;
-use std::{collections::HashMap, io::Read};
+use std::{collections::HashMap, fmt};
 
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::solution::Solution;
 
-fn parse_file(filename: &str) -> anyhow::Result<Vec<u64>> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-    Ok(string
+fn parse_input(input: &str) -> anyhow::Result<Vec<u64>> {
+    Ok(input
         .split_whitespace()
         .map(|s| s.parse::<u64>())
         .collect::<Result<Vec<_>, _>>()?)
@@ -36,7 +26,7 @@ fn process(input: u64) -> Either<u64, [u64; 2]> {
     }
     let digits = num_digits(input);
 
-    if digits % 2 == 0 {
+    if digits.is_multiple_of(2) {
         let splitter = 10u64.pow(digits / 2);
         return Either::Right([input / splitter, input % splitter]);
     }
@@ -65,29 +55,30 @@ fn process_hashed(input: u64, iter: u32, cache: &mut HashMap<(u64, u32), u64>) -
     res
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let mut cache = HashMap::new();
-            let mut res = 0u64;
-            for i in output.iter().cloned() {
-                res += process_hashed(i, 25, &mut cache);
-            }
-            println!("{}", res);
-            Ok(())
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut cache = HashMap::new();
+        let mut res = 0u64;
+        for i in output.iter().cloned() {
+            res += process_hashed(i, 25, &mut cache);
         }
+        Ok(res)
+    }
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
-            let mut cache = HashMap::new();
-            let mut res = 0u64;
-            for i in output.iter().cloned() {
-                res += process_hashed(i, 75, &mut cache);
-            }
-            println!("{}", res);
-            Ok(())
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut cache = HashMap::new();
+        let mut res = 0u64;
+        for i in output.iter().cloned() {
+            res += process_hashed(i, 75, &mut cache);
         }
+        Ok(res)
     }
 }
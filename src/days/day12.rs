@@ -0,0 +1,38 @@
+use std::fmt;
+
+use crate::{
+    grid::{self, components::Connectivity},
+    solution::Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = grid::Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        grid::parse_grid(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let sum = output
+            .components(Connectivity::Four, |a, b| a == b)
+            .regions
+            .iter()
+            .map(|region| region.perimeter() * region.area())
+            .sum::<usize>();
+
+        Ok(sum)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let sum = output
+            .components(Connectivity::Four, |a, b| a == b)
+            .regions
+            .iter()
+            .map(|region| region.sides() * region.area())
+            .sum::<usize>();
+
+        Ok(sum)
+    }
+}
@@ -1,15 +1,6 @@
-use std::{cmp, collections::BTreeSet, io::Read};
+use std::{cmp, collections::BTreeSet, fmt};
 
-use aoc24::parser;
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::{debug, parser, solution::Solution, trace};
 
 fn take_button(chr: char) -> impl Fn(&str) -> Option<((isize, isize), &str)> {
     move |input: &str| {
@@ -36,7 +27,7 @@ fn take_prize() -> impl Fn(&str) -> Option<((isize, isize), &str)> {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Game {
+pub struct Game {
     a: (isize, isize),
     b: (isize, isize),
     prize: (isize, isize),
@@ -86,7 +77,7 @@ fn solve2(game: &Game) -> Option<(f64, f64)> {
     let prize = [game.prize.0 as f64, game.prize.1 as f64];
 
     let Some(a_inverse) = invert2x2(a) else {
-        println!("matrix: a_inverse not found");
+        trace!("matrix: a_inverse not found");
         return None;
     };
     let s = matmul2x2v2(a_inverse, prize);
@@ -96,7 +87,7 @@ fn solve2(game: &Game) -> Option<(f64, f64)> {
 
     let (x, y) = (round(s0)?, round(s1)?);
     if x < 0.0 || y < 0.0 {
-        println!("solution is negative");
+        trace!("solution is negative");
         return None;
     }
 
@@ -146,53 +137,51 @@ fn solutions(a: isize, b: isize, c: isize) -> impl Iterator<Item = (isize, isize
     })
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<Vec<Game>> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-    let (games, _) = take_games()(&string).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+fn parse_input(input: &str) -> anyhow::Result<Vec<Game>> {
+    let (games, _) = take_games()(input).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
     Ok(games)
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let total_cost = output
-                .iter()
-                .enumerate()
-                .filter_map(|(i, game)| solve(game).map(|x| (i, game, x)))
-                .inspect(|(id, game, x)| println!("id: {} x: {:?} game={:?}", id, x, game))
-                .map(|(_, _, s)| cost(s))
-                .sum::<isize>();
-
-            println!("{}", total_cost);
-            Ok(())
-        }
+pub struct Day13;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
-            let total_cost = output
-                .iter()
-                .enumerate()
-                .map(|(i, game)| {
-                    (
-                        i,
-                        Game {
-                            a: game.a,
-                            b: game.b,
-                            prize: (game.prize.0 + 10000000000000, game.prize.1 + 10000000000000),
-                        },
-                    )
-                })
-                .filter_map(|(i, game)| solve2(&game).map(|x| (i, game, x)))
-                .inspect(|(id, game, x)| println!("id: {} x: {:?} game={:?}", id, x, game))
-                .map(|(_, _, s)| cost_f64(s))
-                .sum::<f64>();
-
-            println!("{}", total_cost);
-            Ok(())
-        }
+impl Solution for Day13 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let total_cost = output
+            .iter()
+            .enumerate()
+            .filter_map(|(i, game)| solve(game).map(|x| (i, game, x)))
+            .inspect(|(id, game, x)| debug!("id: {} x: {:?} game={:?}", id, x, game))
+            .map(|(_, _, s)| cost(s))
+            .sum::<isize>();
+
+        Ok(total_cost)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let total_cost = output
+            .iter()
+            .enumerate()
+            .map(|(i, game)| {
+                (
+                    i,
+                    Game {
+                        a: game.a,
+                        b: game.b,
+                        prize: (game.prize.0 + 10000000000000, game.prize.1 + 10000000000000),
+                    },
+                )
+            })
+            .filter_map(|(i, game)| solve2(&game).map(|x| (i, game, x)))
+            .inspect(|(id, game, x)| debug!("id: {} x: {:?} game={:?}", id, x, game))
+            .map(|(_, _, s)| cost_f64(s))
+            .sum::<f64>();
+
+        Ok(total_cost)
     }
 }
@@ -0,0 +1,174 @@
+use crate::{
+    debug,
+    grid::{self, Grid},
+    parser,
+    point::Point,
+    solution::Solution,
+    trace,
+};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug)]
+pub struct Robot {
+    start: Point,
+    velocity: Point,
+}
+
+impl Robot {
+    fn step(&self, steps: usize) -> Point {
+        self.start + self.velocity * steps as isize
+    }
+
+    fn step_wrap(&self, steps: usize, bounds: Point) -> Point {
+        self.step(steps).wrap(bounds)
+    }
+}
+
+fn take_robot<'a>() -> impl Fn(&'a str) -> Option<(Robot, &'a str)> {
+    move |input: &str| {
+        let (_, rest) = parser::take_str("p=")(input)?;
+        let ((p1, _, p2), rest) = parser::take_tuple3(
+            parser::take_int(),
+            parser::take_str(","),
+            parser::take_int(),
+        )(rest)?;
+        let (_, rest) = parser::take_str(" v=")(rest)?;
+        let ((v1, _, v2), rest) = parser::take_tuple3(
+            parser::take_int(),
+            parser::take_str(","),
+            parser::take_int(),
+        )(rest)?;
+        let start = Point(p1 as isize, p2 as isize);
+        let velocity = Point(v1 as isize, v2 as isize);
+        Some((Robot { start, velocity }, rest))
+    }
+}
+
+// The example robots walk an 11x7 room instead of the real 101x103 one.
+fn bounds(robots: &[Robot]) -> Point {
+    let example = Point(11, 7);
+    let fits_example = robots.iter().all(|robot| {
+        (0..example.0).contains(&robot.start.0) && (0..example.1).contains(&robot.start.1)
+    });
+    if fits_example {
+        example
+    } else {
+        Point(101, 103)
+    }
+}
+
+fn in_quadrant(pos: Point, bounds: Point, quadrant: usize) -> bool {
+    let on_left = pos.0 < bounds.0 / 2;
+    let on_right = pos.0 > bounds.0 / 2;
+    let on_top = pos.1 < bounds.1 / 2;
+    let on_bottom = pos.1 > bounds.1 / 2;
+
+    let q = match (on_left, on_right, on_top, on_bottom) {
+        (true, _, true, _) => 0,
+        (true, _, _, true) => 1,
+        (_, true, true, _) => 2,
+        (_, true, _, true) => 3,
+        _ => return false,
+    };
+
+    q == quadrant
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<Robot>> {
+    let (robots, _) = parser::take_separator(take_robot(), parser::take_newline())(input)
+        .and_then(|(robots, rest)| {
+            let (_, rest) = parser::take_eol()(rest)?;
+            Some((robots, rest))
+        })
+        .ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+    Ok(robots)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(robots: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let bounds = bounds(robots);
+        let robot_count = (0usize..4)
+            .map(|quadrant| {
+                robots
+                    .iter()
+                    .filter(|robot| {
+                        let pos = robot.step_wrap(100, bounds);
+                        trace!("{:?}", pos);
+                        in_quadrant(pos, bounds, quadrant)
+                    })
+                    .count()
+            })
+            .collect::<Vec<_>>();
+
+        debug!("{:?}", robot_count);
+        Ok(robot_count.iter().product::<usize>().to_string())
+    }
+
+    fn part2(robots: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let Point(width, height) = bounds(robots);
+        // every robot is back where it started after width * height steps
+        for i in 0..(width * height) as usize {
+            let mut counts = Grid::filled(width as usize, height as usize, 0usize);
+            for robot in robots {
+                let Point(x, y) = robot.step(i);
+                let pos = counts.wrap((y, x));
+                counts[pos] += 1;
+            }
+
+            if max_line_length(&counts) < 30 {
+                continue;
+            }
+
+            let frame = counts.map(|_, count| match *count {
+                0 => '.',
+                count if count < 10 => (b'0' + count as u8) as char,
+                _ => '#',
+            });
+
+            debug!("iter: {}", i);
+            debug!("{}", frame.render());
+            return Ok(i);
+        }
+        Err(anyhow::anyhow!("no frame looks like a christmas tree"))
+    }
+}
+
+fn max_line_length(counts: &Grid<usize>) -> usize {
+    fn check(
+        counts: &Grid<usize>,
+        cached: &mut HashMap<(grid::Index, grid::Direction), usize>,
+        pos: grid::Index,
+        dir: grid::Direction,
+    ) -> usize {
+        if let Some(v) = cached.get(&(pos, dir)) {
+            return *v;
+        }
+        if !matches!(counts.get(pos), Some(count) if *count > 0) {
+            return 0;
+        }
+
+        let res = check(counts, cached, dir.apply(pos), dir) + 1;
+        cached.insert((pos, dir), res);
+        res
+    }
+
+    let mut visited = HashMap::new();
+    let max_distance = counts
+        .iter_pos()
+        .filter(|(_, count)| **count > 0)
+        .flat_map(|(pos, _)| {
+            let visited = &mut visited;
+            grid::Direction::all_directions().map(move |dir| check(counts, visited, pos, dir))
+        })
+        .max();
+
+    max_distance.unwrap_or(0)
+}
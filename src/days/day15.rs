@@ -0,0 +1,266 @@
+use std::{collections::BTreeSet, fmt};
+
+use crate::{
+    debug,
+    grid::{self, render::Cell},
+    parser,
+    solution::Solution,
+    trace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Empty,
+    Box,
+    Wall,
+    Robot,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry2 {
+    Empty,
+    LBox,
+    RBox,
+    Wall,
+    Robot,
+}
+
+impl Cell for Entry2 {
+    fn glyph(&self) -> char {
+        match self {
+            Entry2::Empty => '.',
+            Entry2::Wall => '#',
+            Entry2::Robot => '@',
+            Entry2::LBox => '[',
+            Entry2::RBox => ']',
+        }
+    }
+}
+
+impl TryFrom<char> for Entry {
+    type Error = anyhow::Error;
+
+    fn try_from(chr: char) -> anyhow::Result<Self> {
+        match chr {
+            '.' => Ok(Entry::Empty),
+            '#' => Ok(Entry::Wall),
+            '@' => Ok(Entry::Robot),
+            'O' => Ok(Entry::Box),
+            chr => Err(anyhow::anyhow!("unexpected '{chr}'")),
+        }
+    }
+}
+
+fn take_moves<'a>() -> impl Fn(&'a str) -> Option<(Vec<grid::Direction>, &'a str)> {
+    let take_move = move |input: &'a str| {
+        let (chr, rest) = parser::take_any("<>^v")(input)?;
+        let dir = match chr {
+            '<' => grid::Direction::Left,
+            '>' => grid::Direction::Right,
+            '^' => grid::Direction::Up,
+            'v' => grid::Direction::Down,
+            _ => None?,
+        };
+        Some((dir, rest))
+    };
+
+    parser::take_many1(take_move)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedResult {
+    entries: grid::Grid<Entry>,
+    start: grid::Index,
+    moves: Vec<grid::Direction>,
+}
+
+fn move_to(
+    entries: &mut grid::Grid<Entry>,
+    pos: grid::Index,
+    dir: grid::Direction,
+) -> Option<grid::Index> {
+    let p2 = dir.apply(pos);
+    match entries.get(pos) {
+        Some(Entry::Empty) => Some(p2),
+        Some(entry @ Entry::Box) | Some(entry @ Entry::Robot) => {
+            let entry = *entry;
+            if move_to(entries, p2, dir).is_some() {
+                // SAFETY: we know that the position in the next pos is within the bounds
+                // already through the recursive call
+                *entries.get_mut(p2).unwrap() = entry;
+                // SAFETY: we know that the position in the current pos is within the bounds
+                // already
+                *entries.get_mut(pos).unwrap() = Entry::Empty;
+                Some(p2)
+            } else {
+                None
+            }
+        }
+        None | Some(Entry::Wall) => None,
+    }
+}
+
+fn move_to3(
+    entries: &mut grid::Grid<Entry2>,
+    pos: grid::Index,
+    dir: grid::Direction,
+) -> Option<grid::Index> {
+    // algorithm: first we mark all the positions that have to be moved in unison.
+    let mut visited = BTreeSet::new();
+    let mut to_move = Vec::new();
+    let mut to_visit = BTreeSet::from([pos]);
+    while let Some(cur) = to_visit.pop_first() {
+        let entry = *entries.get(cur)?;
+        if !visited.contains(&cur) {
+            visited.insert(cur);
+        } else {
+            continue;
+        }
+        match entry {
+            Entry2::Empty => {}
+            Entry2::Wall => return None,
+            Entry2::Robot => {
+                let pos2 = dir.apply(cur);
+                to_move.push((cur, entry));
+                to_visit.insert(pos2);
+            }
+            Entry2::LBox | Entry2::RBox if dir.is_horizontal() => {
+                let pos2 = dir.apply(cur);
+                to_move.push((cur, entry));
+                to_visit.insert(pos2);
+            }
+            Entry2::LBox => {
+                let rbox_pos = grid::Direction::Right.apply(cur);
+                if !(visited.contains(&rbox_pos) || to_visit.contains(&rbox_pos)) {
+                    to_visit.insert(rbox_pos);
+                }
+                let pos2 = dir.apply(cur);
+                to_move.push((cur, entry));
+                to_visit.insert(pos2);
+            }
+            Entry2::RBox => {
+                let lbox_pos = grid::Direction::Left.apply(cur);
+                if !(visited.contains(&lbox_pos) || to_visit.contains(&lbox_pos)) {
+                    to_visit.insert(lbox_pos);
+                }
+                let pos2 = dir.apply(cur);
+                to_move.push((cur, entry));
+                to_visit.insert(pos2);
+            }
+        }
+    }
+
+    let mut positions_set = BTreeSet::new();
+    for (cur, entry) in to_move.iter().cloned() {
+        let pos2 = dir.apply(cur);
+        // SAFETY: we know that the position in the next pos is within the bounds
+        // already through the check at line 108
+        if !positions_set.contains(&cur) {
+            *entries.get_mut(cur).unwrap() = Entry2::Empty;
+        }
+        *entries.get_mut(pos2).unwrap() = entry;
+        positions_set.insert(pos2);
+    }
+
+    Some(dir.apply(pos))
+}
+
+fn convert_to_part2(entries: &grid::Grid<Entry>) -> anyhow::Result<grid::Grid<Entry2>> {
+    let cells = entries
+        .iter()
+        .flat_map(|entry| match entry {
+            Entry::Empty => [Entry2::Empty, Entry2::Empty],
+            Entry::Box => [Entry2::LBox, Entry2::RBox],
+            Entry::Wall => [Entry2::Wall, Entry2::Wall],
+            Entry::Robot => [Entry2::Robot, Entry2::Empty],
+        })
+        .collect();
+
+    grid::Grid::new(entries.width() * 2, entries.height(), cells)
+}
+
+fn parse_input(input: &str) -> anyhow::Result<ParsedResult> {
+    let (map, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow::anyhow!("could not find the moves after the map"))?;
+    let (entries, markers) = grid::parse_typed(map, "@")?;
+    let start = markers.one('@')?;
+
+    let (moves, rest) = parser::take_separator(take_moves(), parser::take_newline())(moves)
+        .ok_or_else(|| anyhow::anyhow!("could not parse moves"))?;
+    if parser::take_eol()(rest).is_none() {
+        Err(anyhow::anyhow!("could not parse moves"))?;
+    }
+
+    Ok(ParsedResult {
+        entries,
+        start,
+        moves: moves.into_iter().flatten().collect(),
+    })
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = ParsedResult;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut output = output.clone();
+        let mut cur = output.start;
+        for dir in output.moves {
+            if let Some(p) = move_to(&mut output.entries, cur, dir) {
+                cur = p;
+            }
+        }
+
+        let res = output
+            .entries
+            .iter_pos()
+            .filter_map(|(pos, chr)| {
+                if *chr == Entry::Box {
+                    Some(100 * pos.0 + pos.1)
+                } else {
+                    None
+                }
+            })
+            .sum::<isize>();
+
+        Ok(res)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let ParsedResult {
+            entries,
+            start,
+            moves,
+        } = input;
+        let mut entries = convert_to_part2(entries)?;
+        // the robot keeps the left half of its doubled cell
+        let mut cur = (start.0, start.1 * 2);
+        trace!("{}", entries.render());
+        for &dir in moves {
+            if let Some(p) = move_to3(&mut entries, cur, dir) {
+                cur = p;
+            }
+            trace!("{}", entries.render());
+        }
+
+        let res = entries
+            .iter_pos()
+            .filter_map(|(pos, chr)| {
+                if *chr == Entry2::LBox {
+                    Some(100 * pos.0 + pos.1)
+                } else {
+                    None
+                }
+            })
+            .sum::<isize>();
+
+        debug!("{}", entries.render());
+        Ok(res)
+    }
+}
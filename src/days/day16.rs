@@ -0,0 +1,186 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::{
+    debug,
+    graph::implicit::{self, Predecessors},
+    grid::{
+        self,
+        render::{Cell, Style},
+    },
+    point::Point,
+    solution::Solution,
+    trace,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Item {
+    Start,
+    End,
+    Space,
+    Wall,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedResult {
+    grid: grid::Grid<Item>,
+    start: grid::Index,
+    end: grid::Index,
+}
+
+impl Cell for Item {
+    fn glyph(&self) -> char {
+        match self {
+            Item::Start => 'S',
+            Item::End => 'E',
+            Item::Space => '.',
+            Item::Wall => '#',
+        }
+    }
+}
+
+type Node = (grid::Index, grid::Direction);
+
+impl ParsedResult {
+    fn is_open(&self, pos: grid::Index) -> bool {
+        matches!(self.grid.get(pos), Some(item) if *item != Item::Wall)
+    }
+
+    fn turns((pos, dir): Node) -> impl Iterator<Item = (Node, usize)> {
+        grid::Direction::all_directions()
+            .into_iter()
+            .filter(move |dir2| *dir2 != dir)
+            .map(move |dir2| ((pos, dir2), 1000))
+    }
+
+    fn forward(&self, node: Node) -> impl Iterator<Item = (Node, usize)> + '_ {
+        let (pos, dir) = node;
+        let ahead = dir.apply(pos);
+        let step = self.is_open(ahead).then_some(((ahead, dir), 1));
+        Self::turns(node).chain(step)
+    }
+
+    // a lower bound on the cost to the end: every step still to take, and a
+    // turn unless the end is straight ahead
+    fn heuristic(&self, (pos, dir): Node) -> usize {
+        let delta = (self.end.0 - pos.0, self.end.1 - pos.1);
+        let ahead = (delta.0 == 0 || delta.1 == 0)
+            && grid::Direction::from_delta((delta.0.signum(), delta.1.signum())) == Some(dir);
+        let turn = if pos == self.end || ahead { 0 } else { 1000 };
+        Point::from(pos).manhattan(self.end.into()) + turn
+    }
+
+    // stops as soon as the end is reached in any direction
+    fn shortest_path(&self) -> Option<usize> {
+        let res = implicit::astar(
+            [(self.start, grid::Direction::Right)],
+            |&node| self.forward(node),
+            |&node| self.heuristic(node),
+            |(pos, _)| *pos == self.end,
+            Predecessors::One,
+        );
+        trace!("{:?}", res.distances);
+        debug!("expanded {} nodes", res.expanded);
+        res.goal().map(|(cost, _)| cost)
+    }
+
+    // a position is on a best path when some node there is in the shortest
+    // path DAG to the end
+    fn all_nodes_in_paths(&self) -> BTreeSet<grid::Index> {
+        let search = implicit::dijkstra(
+            [(self.start, grid::Direction::Right)],
+            |&node| self.forward(node),
+            Predecessors::All,
+        );
+        let ends = grid::Direction::all_directions().map(|dir| (self.end, dir));
+        let Some(dag) = search.paths_to(ends) else {
+            return BTreeSet::new();
+        };
+        debug!("{} best paths", dag.count());
+        dag.nodes().map(|(pos, _)| *pos).collect()
+    }
+}
+
+#[allow(unused)]
+fn convert_paths<Node: std::cmp::Ord + Copy>(
+    paths: &BTreeMap<Node, Vec<Node>>,
+    start: Node,
+    end: Node,
+) -> Vec<Vec<Node>> {
+    let mut res = Vec::new();
+    let mut stack: Vec<(Node, Vec<Node>)> = vec![(end, vec![end])];
+
+    while let Some((cur, mut path)) = stack.pop() {
+        if cur == start {
+            path.push(cur);
+            path.reverse();
+            res.push(path);
+            continue;
+        }
+
+        paths.get(&cur).into_iter().flatten().for_each(|n| {
+            stack.push((*n, path.clone()));
+        })
+    }
+
+    res
+}
+
+impl TryFrom<char> for Item {
+    type Error = anyhow::Error;
+
+    fn try_from(chr: char) -> anyhow::Result<Self> {
+        match chr {
+            '.' => Ok(Item::Space),
+            '#' => Ok(Item::Wall),
+            'S' => Ok(Item::Start),
+            'E' => Ok(Item::End),
+            c => Err(anyhow::anyhow!("invalid character: {c}")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> anyhow::Result<ParsedResult> {
+    let (grid, markers) = grid::parse_typed(input, "SE")?;
+
+    Ok(ParsedResult {
+        start: markers.one('S')?,
+        end: markers.one('E')?,
+        grid,
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = ParsedResult;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let res = output
+            .shortest_path()
+            .ok_or_else(|| anyhow::anyhow!("could not find shortest path"))?;
+
+        Ok(res)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let nodes = output.all_nodes_in_paths();
+
+        debug!(
+            "{}",
+            output
+                .grid
+                .render()
+                .highlight(nodes.iter().copied(), Style::glyph('O'))
+        );
+
+        let res = nodes.len();
+        Ok(res)
+    }
+}
@@ -1,30 +1,15 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
-    io::Read,
+    fmt,
 };
 
-use aoc24::parser;
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 {
-        file: String,
-        #[arg(long, action = clap::ArgAction::SetTrue)]
-        debug: bool,
-        #[arg(long, short = 'a')]
-        set_a: Option<i64>,
-    },
-    /// Day 1 part 2
-    Part2 {
-        file: String,
-    },
-
-    Mappings {
-        file: String,
-    },
-}
+use crate::{
+    info,
+    log::{self, Level},
+    parser,
+    solution::Solution,
+    trace,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Register {
@@ -263,7 +248,7 @@ fn convert_combo(a: u8) -> Option<Combo> {
 }
 
 #[derive(Debug)]
-struct Program {
+pub struct Program {
     states: RegisterState,
     instructions: Vec<i64>,
 }
@@ -287,11 +272,8 @@ fn take_program<'a>() -> impl Fn(&'a str) -> Option<(Program, &'a str)> {
     }
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<Program> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-    let (program, _) = take_program()(&string).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+fn parse_input(input: &str) -> anyhow::Result<Program> {
+    let (program, _) = take_program()(input).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
     Ok(program)
 }
 
@@ -337,9 +319,12 @@ fn run_program_iter(
 
             let (new_state, output) = state.apply(&instr);
             if debug {
-                println!(
+                trace!(
                     "instr = {:?}, state = {:?}, new_state = {:?}, output = {:?}",
-                    instr, state, new_state, output
+                    instr,
+                    state,
+                    new_state,
+                    output
                 );
             }
             state = new_state;
@@ -388,108 +373,114 @@ fn run_part2(
     Ok(())
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file, debug, set_a } => {
-            let output = parse_file(&file)?;
-            let mut state = output.states;
-            if let Some(a) = set_a {
-                state.a = a;
-            }
-            let res = run_program_iter(state, &output.instructions, debug)
-                .collect::<anyhow::Result<Vec<_>>>()?;
-
-            println!(
-                "{}",
-                res.into_iter()
-                    .map(|v| v.to_string())
-                    .collect::<Vec<_>>()
-                    .join(",")
-            );
-            Ok(())
-        }
+pub struct Day17;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
-            let mut solutions = BTreeSet::new();
-            run_part2(&output.instructions, 0, 0, &mut solutions)?;
-            println!("{:?}", solutions.first());
-            Ok(())
-        }
+impl Solution for Day17 {
+    type Input = Program;
 
-        Args::Mappings { file } => {
-            let output = parse_file(&file)?;
-
-            // expected sequence of outputs to counts of internal states;
-            let mut results = BTreeMap::<[i64; 3], [i64; 8]>::new();
-            for i in 1024..=1024 * 1024 {
-                let mut state = output.states;
-                state.a = i;
-                let mut cursor = [-1i64; 3];
-
-                let mut cur = i;
-                let mut program = run_program_iter(state, &output.instructions, false);
-                while let Some(Ok(v)) = program.next() {
-                    cursor[0] = cursor[1];
-                    cursor[1] = cursor[2];
-                    cursor[2] = v;
-
-                    results.entry(cursor).or_default()[cur.unsigned_abs() as usize % 8] += 1;
-                    cur /= 8;
-                }
-            }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
 
-            println!("{:?}", results);
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let res = run_program_iter(
+            output.states,
+            &output.instructions,
+            log::enabled(Level::Trace),
+        )
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(res
+            .into_iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(","))
+    }
 
-            let mut mle = 0i64;
-            let mut cursor = [-1i64; 3];
-            let mut buf = Vec::new();
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut solutions = BTreeSet::new();
+        run_part2(&output.instructions, 0, 0, &mut solutions)?;
+        let res = solutions
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow::anyhow!("could not find a quine"))?;
+        Ok(res)
+    }
+}
 
-            for expected in output.instructions.iter().rev() {
-                buf.clear();
+/// Exploratory analysis used while reverse engineering part 2: tallies which
+/// low three bits of A produce each window of three outputs and prints the
+/// most likely value of A.
+pub fn mappings(input: &str) -> anyhow::Result<()> {
+    let output = parse_input(input)?;
+
+    // expected sequence of outputs to counts of internal states;
+    let mut results = BTreeMap::<[i64; 3], [i64; 8]>::new();
+    for i in 1024..=1024 * 1024 {
+        let mut state = output.states;
+        state.a = i;
+        let mut cursor = [-1i64; 3];
+
+        let mut cur = i;
+        let mut program = run_program_iter(state, &output.instructions, false);
+        while let Some(Ok(v)) = program.next() {
+            cursor[0] = cursor[1];
+            cursor[1] = cursor[2];
+            cursor[2] = v;
+
+            results.entry(cursor).or_default()[cur.unsigned_abs() as usize % 8] += 1;
+            cur /= 8;
+        }
+    }
 
-                cursor[0] = cursor[1];
-                cursor[1] = cursor[2];
-                cursor[2] = *expected;
+    info!("{:?}", results);
 
-                let iter = results
-                    .get(&cursor)
-                    .ok_or_else(|| anyhow::anyhow!("could not find expected {:?}", cursor))?
-                    .iter()
-                    .enumerate();
+    let mut mle = 0i64;
+    let mut cursor = [-1i64; 3];
+    let mut buf = Vec::new();
 
-                for (i, v) in iter {
-                    buf.push((v, i));
-                }
+    for expected in output.instructions.iter().rev() {
+        buf.clear();
 
-                buf.sort();
+        cursor[0] = cursor[1];
+        cursor[1] = cursor[2];
+        cursor[2] = *expected;
 
-                println!("{:?}", buf);
-                let idx = buf[7].1;
-                mle += mle * 8 + idx as i64;
-            }
+        let iter = results
+            .get(&cursor)
+            .ok_or_else(|| anyhow::anyhow!("could not find expected {:?}", cursor))?
+            .iter()
+            .enumerate();
 
-            println!("mle: {}", mle);
-
-            let mut state = output.states;
-            state.a = mle;
-            let outputs = run_program(state, &output.instructions)?
-                .into_iter()
-                .map(|v| v.to_string())
-                .collect::<Vec<_>>()
-                .join(",");
-            println!("got: {}", outputs);
-            println!(
-                "expected: {}",
-                output
-                    .instructions
-                    .iter()
-                    .map(|v| v.to_string())
-                    .collect::<Vec<_>>()
-                    .join(",")
-            );
-            Ok(())
+        for (i, v) in iter {
+            buf.push((v, i));
         }
+
+        buf.sort();
+
+        info!("{:?}", buf);
+        let idx = buf[7].1;
+        mle += mle * 8 + idx as i64;
     }
+
+    info!("mle: {}", mle);
+
+    let mut state = output.states;
+    state.a = mle;
+    let outputs = run_program(state, &output.instructions)?
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    info!("got: {}", outputs);
+    info!(
+        "expected: {}",
+        output
+            .instructions
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    Ok(())
 }
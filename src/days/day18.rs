@@ -0,0 +1,126 @@
+use std::fmt;
+
+use crate::{
+    grid::{self, search::Predecessors, Grid},
+    log::{self, Level},
+    parser,
+    solution::Solution,
+    trace,
+};
+
+fn take_line<'a>() -> impl Fn(&'a str) -> Option<(grid::Index, &'a str)> {
+    move |input: &str| {
+        let (i, rest) = parser::take_int()(input)?;
+        let (_, rest) = parser::take_str(",")(rest)?;
+        let (j, rest) = parser::take_int()(rest)?;
+        let (_, rest) = parser::take_newline()(rest)?;
+        Some(((i as isize, j as isize), rest))
+    }
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<grid::Index>> {
+    let (grid, rest) =
+        parser::take_many1(take_line())(input).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+    if !rest.is_empty() {
+        Err(anyhow::anyhow!("could not parse: {rest}"))?;
+    }
+
+    Ok(grid)
+}
+
+// The example is a 7x7 memory space where only 12 bytes fall before part 1,
+// instead of the real 71x71 space and 1024 bytes.
+fn memory_size(drops: &[grid::Index]) -> (isize, usize) {
+    let fits_example = drops
+        .iter()
+        .all(|&(i, j)| (0..=6).contains(&i) && (0..=6).contains(&j));
+    if fits_example {
+        (6, 12)
+    } else {
+        (70, 1024)
+    }
+}
+
+// each cell holds the index of the first drop to land on it, if any.
+fn build_memory(drops: &[grid::Index]) -> Grid<Option<usize>> {
+    let (shape, _) = memory_size(drops);
+    let size = shape as usize + 1;
+    let mut memory = Grid::filled(size, size, None);
+    for (i, &pos) in drops.iter().enumerate() {
+        if let Some(cell) = memory.get_mut(pos) {
+            cell.get_or_insert(i);
+        }
+    }
+    memory
+}
+
+// runs with every drop up to and including index `value` in place.
+fn run_with_drops(memory: &Grid<Option<usize>>, value: usize) -> Option<usize> {
+    let end = (memory.height() as isize - 1, memory.width() as isize - 1);
+    memory
+        .astar(
+            (0, 0),
+            end,
+            |_, drop| drop.is_none_or(|drop| drop > value).then_some(1),
+            Predecessors::None,
+        )
+        .distance(&end)
+}
+
+// binsearch to the first value that is false.
+// low may or may not be the first value that is false.
+// high must be false.
+fn binsearch(memory: &Grid<Option<usize>>, low: usize, high: usize) -> Option<usize> {
+    if low >= high {
+        if run_with_drops(memory, low).is_none() {
+            return Some(low);
+        };
+        return None;
+    }
+
+    let mid = low + (high - low) / 2;
+    let res = run_with_drops(memory, mid).is_some();
+
+    if res {
+        binsearch(memory, mid + 1, high)
+    } else {
+        binsearch(memory, low, mid)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<grid::Index>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let (_, fallen) = memory_size(output);
+        let memory = build_memory(output);
+        let distance =
+            run_with_drops(&memory, fallen - 1).ok_or_else(|| anyhow::anyhow!("could not find"))?;
+        Ok(distance)
+    }
+
+    fn part2(drops: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let memory = build_memory(drops);
+        let search = binsearch(&memory, 0, drops.len() - 1)
+            .ok_or_else(|| anyhow::anyhow!("could not find"))?;
+
+        // re-runs the search for every drop, so only when someone is looking
+        if log::enabled(Level::Trace) {
+            for (i, drop) in drops.iter().enumerate() {
+                let res = run_with_drops(&memory, i);
+                trace!("i = {}, {:?}: {:?}", i, drop, res);
+                if search == i {
+                    trace!("found at i = {}", i);
+                }
+            }
+        }
+
+        Ok(format!("{},{}", drops[search].0, drops[search].1))
+    }
+}
@@ -1,15 +1,6 @@
-use std::io::Read;
+use std::fmt;
 
-use aoc24::parser;
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::{parser, solution::Solution};
 
 type Pattern = Vec<u8>;
 fn take_pattern<'a>() -> impl Fn(&'a str) -> Option<(Pattern, &'a str)> {
@@ -36,7 +27,7 @@ fn take_options<'a>() -> impl Fn(&'a str) -> Option<(Vec<Pattern>, &'a str)> {
 }
 
 #[derive(Debug)]
-struct ParsedResult {
+pub struct ParsedResult {
     options: Vec<Pattern>,
     checks: Vec<Pattern>,
 }
@@ -54,11 +45,8 @@ fn take_file<'a>() -> impl Fn(&'a str) -> Option<(ParsedResult, &'a str)> {
     }
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<ParsedResult> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-    let (result, rest) = take_file()(&string).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+fn parse_input(input: &str) -> anyhow::Result<ParsedResult> {
+    let (result, rest) = take_file()(input).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
     if !rest.is_empty() {
         Err(anyhow::anyhow!("could not parse: {rest}"))?;
     }
@@ -130,31 +118,32 @@ fn dp_check2(patterns: &[Pattern], haystack: &[u8], dp: &mut Vec<Option<usize>>)
     sum
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let count = output
-                .checks
-                .iter()
-                .filter(|p| check(&output.options, p))
-                .count();
-
-            println!("{}", count);
-            Ok(())
-        }
+pub struct Day19;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
-            let sum = output
-                .checks
-                .iter()
-                .map(|p| check2(&output.options, p))
-                .sum::<usize>();
+impl Solution for Day19 {
+    type Input = ParsedResult;
 
-            println!("{}", sum);
-            Ok(())
-        }
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let count = output
+            .checks
+            .iter()
+            .filter(|p| check(&output.options, p))
+            .count();
+
+        Ok(count)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let sum = output
+            .checks
+            .iter()
+            .map(|p| check2(&output.options, p))
+            .sum::<usize>();
+
+        Ok(sum)
     }
 }
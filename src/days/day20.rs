@@ -0,0 +1,193 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use crate::{
+    debug,
+    grid::{self, search::Predecessors},
+    point::Point,
+    solution::Solution,
+    trace,
+};
+
+// solution idea:
+// 1. bfs from the start and from the end along the track
+// 2. a cheat from s1 to s2 costs the manhattan distance between them, so the
+//    whole race takes dist(start, s1) + |s2 - s1| + dist(s2, end)
+
+fn find(grid: &grid::Grid<char>, target: char) -> anyhow::Result<grid::Index> {
+    let res = grid
+        .iter_pos()
+        .find(|(_, c)| **c == target)
+        .ok_or_else(|| anyhow::anyhow!("could not find {target}"))?
+        .0;
+
+    Ok(res)
+}
+
+#[allow(unused)]
+struct RunResult {
+    start: grid::Index,
+    end: grid::Index,
+    counts: BTreeMap<usize, BTreeSet<(grid::Index, grid::Index)>>,
+    default_distance: usize,
+}
+
+fn run_problem(output: &grid::Grid<char>, max_seconds: usize) -> anyhow::Result<RunResult> {
+    let start = find(output, 'S')?;
+    let end = find(output, 'E')?;
+
+    let on_track = |_: &char, to: &char| *to != '#';
+    let distance_from_start = output.bfs(start, on_track, Predecessors::None);
+    let distance_to_end = output.bfs(end, on_track, Predecessors::None);
+
+    let default_distance = distance_from_start
+        .distance(&end)
+        .ok_or_else(|| anyhow::anyhow!("could not find distance"))?;
+
+    let mut counts = BTreeMap::<usize, BTreeSet<(grid::Index, grid::Index)>>::new();
+    for (s1, _) in output.iter_pos() {
+        let Some(d1) = distance_from_start.distance(&s1) else {
+            continue;
+        };
+        for delta in grid::Neighbourhood::Diamond(max_seconds).offsets() {
+            let s2 = (Point::from(s1) + delta).into();
+            let Some(d2) = distance_to_end.distance(&s2) else {
+                continue;
+            };
+
+            let dist = d1 + d2 + delta.manhattan(Point::ZERO);
+            if dist < default_distance {
+                counts
+                    .entry(default_distance - dist)
+                    .or_default()
+                    .insert((s1, s2));
+            }
+        }
+    }
+
+    Ok(RunResult {
+        start,
+        end,
+        counts,
+        default_distance,
+    })
+}
+
+// the least time a cheat has to save to count, for each part. Nothing saves
+// 100 on the small example, so there the answers are checked against the
+// puzzle's tables instead: every two second cheat, and twenty second cheats
+// that save at least 50.
+fn min_saving(track: &grid::Grid<char>) -> [usize; 2] {
+    if track.width() < 100 {
+        [1, 50]
+    } else {
+        [100, 100]
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = grid::Grid<char>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        grid::parse_grid(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let res = run_problem(output, 2)?;
+        let [at_least, _] = min_saving(output);
+
+        debug!("default: {}", res.default_distance);
+        debug!("end loc: {:?}", res.end);
+        for (dist, pairs) in res.counts.iter() {
+            trace!("{dist}: {:} - {:?}", pairs.len(), pairs);
+        }
+
+        let cheats = res
+            .counts
+            .range(at_least..)
+            .map(|d| d.1.len())
+            .sum::<usize>();
+        Ok(cheats)
+    }
+
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let res = run_problem(output, 20)?;
+        let [_, at_least] = min_saving(output);
+
+        debug!("default: {}", res.default_distance);
+        debug!("end loc: {:?}", res.end);
+        for (dist, pairs) in res.counts.iter() {
+            trace!("{dist}: {:} - {:?}", pairs.len(), pairs);
+        }
+
+        let cheats = res
+            .counts
+            .range(at_least..)
+            .map(|d| d.1.len())
+            .sum::<usize>();
+        Ok(cheats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // how many cheats save each amount of time, as listed in the puzzle
+    fn savings(res: &RunResult, at_least: usize) -> Vec<(usize, usize)> {
+        res.counts
+            .range(at_least..)
+            .map(|(saved, cheats)| (*saved, cheats.len()))
+            .collect()
+    }
+
+    #[test]
+    fn cheats_test() {
+        let example =
+            grid::parse_grid(include_str!("../../tests/data/day20/example1.txt")).unwrap();
+
+        let short = run_problem(&example, 2).unwrap();
+        assert_eq!(short.default_distance, 84);
+        assert_eq!(
+            savings(&short, 0),
+            [
+                (2, 14),
+                (4, 14),
+                (6, 2),
+                (8, 4),
+                (10, 2),
+                (12, 3),
+                (20, 1),
+                (36, 1),
+                (38, 1),
+                (40, 1),
+                (64, 1)
+            ]
+        );
+
+        let long = run_problem(&example, 20).unwrap();
+        assert_eq!(
+            savings(&long, 50),
+            [
+                (50, 32),
+                (52, 31),
+                (54, 29),
+                (56, 39),
+                (58, 25),
+                (60, 23),
+                (62, 20),
+                (64, 19),
+                (66, 12),
+                (68, 14),
+                (70, 12),
+                (72, 22),
+                (74, 4),
+                (76, 3)
+            ]
+        );
+    }
+}
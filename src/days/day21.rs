@@ -1,22 +1,15 @@
-use anyhow::Result;
-use aoc24::{
-    graph::{self},
+use crate::{
+    debug,
+    graph::{self, implicit::Predecessors, Graph},
     grid::{self, Direction},
     parser,
+    solution::Solution,
 };
-use clap::Parser;
-use std::{collections::BTreeMap, io::Read, rc::Rc, sync::OnceLock};
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use anyhow::Result;
+use std::{collections::BTreeMap, fmt, hash::Hash, rc::Rc, sync::OnceLock};
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
-enum NumberPad {
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub enum NumberPad {
     Number(u8),
     #[default]
     A,
@@ -83,16 +76,7 @@ impl NumberPad {
                 }
             }
 
-            let mut res = BTreeMap::new();
-            let apsp = graph::all_pairs_shortest_paths(&graph);
-            for (n1, distances) in apsp.iter() {
-                for (n2, _) in distances.iter() {
-                    let paths = graph::all_paths(&graph, distances, **n1, **n2);
-                    res.insert((**n1, **n2), graph::paths_to_vecs(&paths, **n1, **n2));
-                }
-            }
-
-            res
+            all_shortest_paths(&graph)
         }
 
         static NUMBERPAD_SHORTEST_PATHS: OnceLock<ShortestPathCache<NumberPad>> = OnceLock::new();
@@ -113,7 +97,25 @@ impl NumberPad {
 
 type ShortestPathCache<N> = BTreeMap<(N, N), Vec<Vec<N>>>;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default)]
+// every shortest path between every pair of keys
+fn all_shortest_paths<N: Ord + Hash + Copy>(graph: &Graph<N>) -> ShortestPathCache<N> {
+    let mut res = BTreeMap::new();
+    for n1 in graph::nodes(graph) {
+        let search = graph::search(graph, n1, Predecessors::All);
+        for n2 in search.distances.keys() {
+            let paths = search
+                .all_shortest_paths_to(n2)
+                .expect("all predecessors are kept");
+            let paths = paths
+                .into_iter()
+                .map(|path| path.into_iter().copied().collect());
+            res.insert((*n1, **n2), paths.collect());
+        }
+    }
+    res
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
 enum ArrowPad {
     Direction(grid::Direction),
     #[default]
@@ -154,17 +156,7 @@ impl ArrowPad {
                 }
             }
 
-            // for each node, find the shortest path to each neighbor
-            let mut res = BTreeMap::new();
-            let apsp = graph::all_pairs_shortest_paths(&graph);
-            for (a1, distances) in apsp.iter() {
-                for (a2, _) in distances.iter() {
-                    let paths = graph::all_paths(&graph, distances, **a1, **a2);
-                    res.insert((**a1, **a2), graph::paths_to_vecs(&paths, **a1, **a2));
-                }
-            }
-
-            res
+            all_shortest_paths(&graph)
         }
 
         static ARROWPAD_SHORTEST_PATHS: OnceLock<ShortestPathCache<ArrowPad>> = OnceLock::new();
@@ -220,16 +212,12 @@ fn neighbors<const N: usize, const M: usize, T: Eq + Clone>(
         })
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<Vec<Vec<NumberPad>>> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut str = String::new();
-    file.read_to_string(&mut str)?;
-
+fn parse_input(input: &str) -> anyhow::Result<Vec<Vec<NumberPad>>> {
     let take_line = parser::take_many1(take_numberpad());
     let take_lines = parser::take_separator(take_line, parser::take_newline());
     let take_input = parser::take_first(take_lines, parser::take_eol());
 
-    let (lines, _) = take_input(str.as_str()).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
+    let (lines, _) = take_input(input).ok_or_else(|| anyhow::anyhow!("could not parse"))?;
 
     Ok(lines)
 }
@@ -415,39 +403,40 @@ fn convert_numberpad_path_to_number(path: &[NumberPad]) -> u64 {
         })
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let res = parse_file(&file)?;
-            let mut cache = Cache::new();
-
-            let mut sum = 0u64;
-            for path in res.iter() {
-                let number = convert_numberpad_path_to_number(path);
-                let arrowpads = solve_path(&mut cache, path, 2)?;
-                println!("{} * {}", arrowpads, number);
-                sum += number * arrowpads as u64;
-            }
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Vec<NumberPad>>;
 
-            println!("{}", sum);
-            Ok(())
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(res: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut cache = Cache::new();
+
+        let mut sum = 0u64;
+        for path in res.iter() {
+            let number = convert_numberpad_path_to_number(path);
+            let arrowpads = solve_path(&mut cache, path, 2)?;
+            debug!("{} * {}", arrowpads, number);
+            sum += number * arrowpads as u64;
         }
 
-        Args::Part2 { file } => {
-            let res = parse_file(&file)?;
-            let mut cache = Cache::new();
+        Ok(sum)
+    }
 
-            let mut sum = 0u64;
-            for path in res.iter() {
-                let number = convert_numberpad_path_to_number(path);
-                let arrowpads = solve_path(&mut cache, path, 25)?;
-                println!("{} * {}", arrowpads, number);
-                sum += number * arrowpads as u64;
-            }
+    fn part2(res: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut cache = Cache::new();
 
-            println!("{}", sum);
-            Ok(())
+        let mut sum = 0u64;
+        for path in res.iter() {
+            let number = convert_numberpad_path_to_number(path);
+            let arrowpads = solve_path(&mut cache, path, 25)?;
+            debug!("{} * {}", arrowpads, number);
+            sum += number * arrowpads as u64;
         }
+
+        Ok(sum)
     }
 }
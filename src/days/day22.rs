@@ -1,24 +1,7 @@
 use itertools::Itertools;
-use std::{collections::BTreeMap, io::BufRead, iter};
+use std::{collections::BTreeMap, fmt, iter};
 
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
-
-fn parse_file(filename: &str) -> anyhow::Result<Vec<u64>> {
-    let file = std::fs::File::open(filename)?;
-    let bufreader = std::io::BufReader::new(file);
-    bufreader
-        .lines()
-        .map(|line| Ok(line?.parse::<u64>()?))
-        .collect()
-}
+use crate::{debug, solution::Solution, trace};
 
 fn mix(secret: u64, input: u64) -> u64 {
     secret ^ input
@@ -73,50 +56,54 @@ fn changes(iter: impl Iterator<Item = i8>) -> impl Iterator<Item = (i8, [i8; 4])
     either::Either::Right(res)
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let input = parse_file(&file)?;
-
-            let mut sum = 0u64;
-            for start in input {
-                let mut secret = start;
-                secret = next_iter(secret).take(2000).last().unwrap();
-                println!("{}: {}", start, secret);
-                sum += secret;
-            }
+pub struct Day22;
 
-            println!("{}", sum);
-            Ok(())
-        }
+impl Solution for Day22 {
+    type Input = Vec<u64>;
 
-        Args::Part2 { file } => {
-            let output = parse_file(&file)?;
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        input.lines().map(|line| Ok(line.parse::<u64>()?)).collect()
+    }
 
-            let mut res = BTreeMap::<[i8; 4], Vec<i8>>::new();
-            for secret in output {
-                let mut sequences = BTreeMap::new();
-                let iter = changes(next_iter(secret).map(price).take(2000));
-                for (price, seq) in iter {
-                    sequences.entry(seq).or_insert(price);
-                }
+    fn part1(input: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut sum = 0u64;
+        for &start in input {
+            let mut secret = start;
+            secret = next_iter(secret).nth(2000).unwrap();
+            trace!("{}: {}", start, secret);
+            sum += secret;
+        }
 
-                for (seq, price) in sequences.into_iter() {
-                    res.entry(seq).or_default().push(price);
-                }
-            }
+        Ok(sum)
+    }
 
-            let (max_seq, soln) = res
-                .into_iter()
-                .max_by_key(|(_, prices)| prices.iter().map(|x| *x as i32).sum::<i32>())
-                .ok_or_else(|| anyhow::anyhow!("could not find max"))?;
+    fn part2(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let mut res = BTreeMap::<[i8; 4], Vec<i8>>::new();
+        for &secret in output {
+            let mut sequences = BTreeMap::new();
+            let iter = changes(next_iter(secret).map(price).take(2001));
+            for (price, seq) in iter {
+                sequences.entry(seq).or_insert(price);
+            }
 
-            println!("{:?}", max_seq);
-            println!("{:?}", soln);
-            println!("{}", soln.iter().cloned().map(|x| x as i32).sum::<i32>());
-            Ok(())
+            for (seq, price) in sequences.into_iter() {
+                res.entry(seq).or_default().push(price);
+            }
         }
+
+        let (max_seq, soln) = res
+            .into_iter()
+            .max_by_key(|(_, prices)| prices.iter().map(|x| *x as i32).sum::<i32>())
+            .ok_or_else(|| anyhow::anyhow!("could not find max"))?;
+
+        debug!("{:?}", max_seq);
+        debug!("{:?}", soln);
+        Ok(soln
+            .iter()
+            .cloned()
+            .map(|x| x as i32)
+            .sum::<i32>()
+            .to_string())
     }
 }
 
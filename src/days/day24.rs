@@ -1,20 +1,10 @@
 use std::{
     collections::{BTreeMap, BTreeSet},
     fmt::{self, Write},
-    io::Read,
     ops::RangeBounds,
 };
 
-use aoc24::parser;
-use clap::Parser;
-
-#[derive(Debug, clap::Parser)]
-enum Args {
-    /// Day 1 part 1
-    Part1 { file: String },
-    /// Day 1 part 2
-    Part2 { file: String },
-}
+use crate::{debug, parser, solution::Solution};
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 struct Node([u8; 3]);
@@ -134,7 +124,7 @@ fn take_final_node<'a>() -> impl Fn(&'a str) -> Option<(Operand, &'a str)> {
 }
 
 #[derive(Debug)]
-struct Circuit {
+pub struct Circuit {
     nodes: BTreeMap<Node, usize>,
     starts: BTreeMap<Node, bool>,
     ops: BTreeMap<Node, Operand>,
@@ -209,7 +199,7 @@ impl Circuit {
         self.nodes
             .range(Node::new([b'z', b'0', b'0'])..=Node::new([b'z', b'9', b'9']))
             .map(|v| v.0)
-            .last()
+            .next_back()
             .and_then(|node| node.position())
     }
 
@@ -364,18 +354,24 @@ impl<'a> EvaluateV2<'a> {
 }
 
 fn create_tests(z: u8) -> impl Iterator<Item = [u64; 2]> {
-    let tests = [
-        [1 << z, 1 << z],             // test that summing sets the bit to zero
-        [1 << z, 0],                  // test that summing with 0 is zero
-        [0, 0],                       // test that 0+0 is zero
-        [1 << (z - 1), 1 << (z - 1)], // test that the carry works
-        [(1 << z) - 1, 1],            // test that the carry works
+    let mut tests = vec![
+        [1 << z, 1 << z],  // test that summing sets the bit to zero
+        [1 << z, 0],       // test that summing with 0 is zero
+        [0, 0],            // test that 0+0 is zero
+        [(1 << z) - 1, 1], // test that the carry works
     ];
-
-    tests.into_iter().chain(tests.into_iter().map(|mut x| {
-        x.reverse();
-        x
-    }))
+    // bit 0 has no lower bit to carry in from
+    if z > 0 {
+        tests.push([1 << (z - 1), 1 << (z - 1)]); // test that the carry works
+    }
+
+    tests
+        .clone()
+        .into_iter()
+        .chain(tests.into_iter().map(|mut x| {
+            x.reverse();
+            x
+        }))
 }
 
 fn validate<F>(max_bit: u8, eval: &F) -> bool
@@ -419,7 +415,7 @@ fn solve_dfs(
 
     let mut swaps = swaps.to_vec();
     for z in min_z..max_z {
-        println!("trying z{:02} with swaps = {:?}", z, swaps);
+        debug!("trying z{:02} with swaps = {:?}", z, swaps);
         let Some(eval) = circuit.evaluate_swapped(&swaps) else {
             return Ok(None);
         };
@@ -447,7 +443,7 @@ fn solve_dfs(
             }
         }
 
-        println!("no solution for z{:02} with swaps {:?}", z, swaps);
+        debug!("no solution for z{:02} with swaps {:?}", z, swaps);
         return Ok(None);
     }
 
@@ -467,14 +463,9 @@ fn valid_swap_candidate(swaps: &[(Node, Node)], swap: (Node, Node)) -> bool {
         && swaps_iter(swaps).all(|existing_swap| existing_swap != swap.1)
 }
 
-fn parse_file(filename: &str) -> anyhow::Result<Circuit> {
-    let mut file = std::fs::File::open(filename)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-
-    let (starts, rest) =
-        parser::take_separator(take_initial_node(), parser::take_newline())(&string)
-            .ok_or_else(|| anyhow::anyhow!("could not parse file"))?;
+fn parse_input(input: &str) -> anyhow::Result<Circuit> {
+    let (starts, rest) = parser::take_separator(take_initial_node(), parser::take_newline())(input)
+        .ok_or_else(|| anyhow::anyhow!("could not parse file"))?;
 
     let (_, rest) = parser::take_newline()(rest)
         .ok_or_else(|| anyhow::anyhow!("could not parse file, remaining: {rest}"))?;
@@ -488,38 +479,39 @@ fn parse_file(filename: &str) -> anyhow::Result<Circuit> {
     Ok(Circuit::new(starts, ops))
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
-    match args {
-        Args::Part1 { file } => {
-            let output = parse_file(&file)?;
-            let eval = output
-                .evaluate()
-                .ok_or_else(|| anyhow::anyhow!("cycle detected"))?
-                .exec_default()?;
-            println!("{}", eval);
-            Ok(())
-        }
+pub struct Day24;
 
-        Args::Part2 { file } => {
-            let circuit = parse_file(&file)?;
-            let nodes = solve(&circuit)?
-                .into_iter()
-                .flat_map(|x| [x.0, x.1])
-                .collect::<BTreeSet<_>>();
-
-            let mut s = String::new();
-            let mut first = true;
-            for node in nodes.iter() {
-                if !first {
-                    s.push(',');
-                }
-                let _ = write!(s, "{}", node);
-                first = false;
-            }
+impl Solution for Day24 {
+    type Input = Circuit;
 
-            println!("{}", s);
-            Ok(())
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let eval = output
+            .evaluate()
+            .ok_or_else(|| anyhow::anyhow!("cycle detected"))?
+            .exec_default()?;
+        Ok(eval)
+    }
+
+    fn part2(circuit: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let nodes = solve(circuit)?
+            .into_iter()
+            .flat_map(|x| [x.0, x.1])
+            .collect::<BTreeSet<_>>();
+
+        let mut s = String::new();
+        let mut first = true;
+        for node in nodes.iter() {
+            if !first {
+                s.push(',');
+            }
+            let _ = write!(s, "{}", node);
+            first = false;
         }
+
+        Ok(s)
     }
 }
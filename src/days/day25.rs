@@ -0,0 +1,113 @@
+use std::fmt;
+
+use crate::{grid, solution::Solution};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Puzzle {
+    is_key: bool,
+    pins: [u8; 5],
+}
+
+impl Puzzle {
+    fn matches(&self, other: &Self) -> bool {
+        if self.is_key == other.is_key {
+            return false;
+        }
+
+        let (key, lock) = if self.is_key {
+            (self, other)
+        } else {
+            (other, self)
+        };
+
+        key.pins
+            .iter()
+            .cloned()
+            .zip(lock.pins.iter().cloned())
+            .all(|(k, l)| pin_match(k, l))
+    }
+}
+
+fn pin_match(key: u8, lock: u8) -> bool {
+    let lock_space = 6u8.wrapping_sub(lock);
+    key < lock_space
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pin {
+    Filled,
+    Empty,
+}
+
+impl TryFrom<char> for Pin {
+    type Error = anyhow::Error;
+
+    fn try_from(chr: char) -> anyhow::Result<Self> {
+        match chr {
+            '#' => Ok(Pin::Filled),
+            '.' => Ok(Pin::Empty),
+            chr => Err(anyhow::anyhow!("unexpected '{chr}'")),
+        }
+    }
+}
+
+fn parse_schematic(input: &str) -> anyhow::Result<Puzzle> {
+    let (pins, _) = grid::parse_typed::<Pin>(input, "")?;
+    if (pins.width(), pins.height()) != (5, 7) {
+        anyhow::bail!(
+            "expected a 5x7 schematic, got {}x{}",
+            pins.width(),
+            pins.height()
+        );
+    }
+
+    // keys are locks upside down, with their pins hanging from the bottom row
+    let is_key = pins
+        .row(0)
+        .is_some_and(|row| row.iter().all(|p| *p == Pin::Empty));
+    let pins = if is_key { pins.flip_vertical() } else { pins };
+
+    let mut heights = [0u8; 5];
+    for (height, column) in heights.iter_mut().zip(pins.columns()) {
+        let column = column.collect::<Vec<_>>();
+        let filled = column.iter().take_while(|p| ***p == Pin::Filled).count();
+        if filled == 0 || column[filled..].iter().any(|p| **p == Pin::Filled) {
+            anyhow::bail!("schematic is neither a key nor a lock");
+        }
+        *height = filled as u8 - 1;
+    }
+
+    Ok(Puzzle {
+        is_key,
+        pins: heights,
+    })
+}
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<Puzzle>> {
+    input.split("\n\n").map(parse_schematic).collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<Puzzle>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(output: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        let keys = output.iter().filter(|p| p.is_key).collect::<Vec<_>>();
+        let locks = output.iter().filter(|p| !p.is_key).collect::<Vec<_>>();
+        let matches = itertools::iproduct!(keys, locks)
+            .filter(|(k, l)| k.matches(l))
+            .count();
+
+        Ok(matches)
+    }
+
+    fn part2(_input: &Self::Input) -> anyhow::Result<impl fmt::Display> {
+        // There's no part 2 for this one
+        Ok("42")
+    }
+}
@@ -0,0 +1,355 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// Which predecessors a search keeps track of on top of distances.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Predecessors {
+    None,
+    /// One node each node was reached from along a shortest path, which is
+    /// enough for [`Search::path_to`].
+    One,
+    /// Every node each node can be reached from along a shortest path, for
+    /// [`Search::all_shortest_paths_to`] as well.
+    All,
+}
+
+/// What a search found: the distance to every node it reached, the
+/// predecessors it was asked to keep, and the target it stopped at, if any.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    pub distances: HashMap<N, usize>,
+    /// Kept for [`Predecessors::One`] and [`Predecessors::All`].
+    pub predecessors: HashMap<N, N>,
+    /// Kept for [`Predecessors::All`] only.
+    pub all_predecessors: HashMap<N, Vec<N>>,
+    pub reached: Option<N>,
+    /// How many nodes had their successors asked for, which is how much work
+    /// the search did.
+    pub expanded: usize,
+    pub(super) record: Predecessors,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    pub(crate) fn new(record: Predecessors) -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            all_predecessors: HashMap::new(),
+            reached: None,
+            expanded: 0,
+            record,
+        }
+    }
+
+    // notes a step from `from` into `node` along a shortest way there, which
+    // either beats every earlier way or ties with the best of them
+    fn record(&mut self, node: &N, from: &N, better: bool) {
+        if self.record == Predecessors::None {
+            return;
+        }
+        if better {
+            self.predecessors.insert(node.clone(), from.clone());
+        }
+        if self.record == Predecessors::All {
+            let all = self.all_predecessors.entry(node.clone()).or_default();
+            if better {
+                all.clear();
+            }
+            if !all.contains(from) {
+                all.push(from.clone());
+            }
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).copied()
+    }
+
+    /// One shortest path from a start to `node`, both ends included, if the
+    /// search kept predecessors and reached it.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if self.record == Predecessors::None {
+            return None;
+        }
+        self.distances.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self
+            .predecessors
+            .get(path.last().expect("path is not empty"))
+        {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from a start to `node`, if the search kept all
+    /// predecessors and reached it. There can be exponentially many, and
+    /// infinitely many if steps that cost nothing go round in a circle, in
+    /// which case this is `None` too.
+    pub fn all_shortest_paths_to(&self, node: &N) -> Option<Vec<Vec<N>>> {
+        if self.record != Predecessors::All {
+            return None;
+        }
+        self.distances.get(node)?;
+        let mut paths = Vec::new();
+        // paths are built backwards from `node` until they reach a start
+        let mut stack = vec![vec![node.clone()]];
+        while let Some(mut path) = stack.pop() {
+            match self
+                .all_predecessors
+                .get(path.last().expect("path is not empty"))
+            {
+                Some(prevs) => {
+                    for prev in prevs {
+                        if path.contains(prev) {
+                            return None;
+                        }
+                        let mut longer = path.clone();
+                        longer.push(prev.clone());
+                        stack.push(longer);
+                    }
+                }
+                None => {
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+        Some(paths)
+    }
+
+    /// The cost of and a cheapest path to the target the search stopped at,
+    /// if it kept predecessors.
+    pub fn goal(&self) -> Option<(usize, Vec<N>)> {
+        let reached = self.reached.as_ref()?;
+        Some((self.distance(reached)?, self.path_to(reached)?))
+    }
+}
+
+/// Breadth first search over the nodes `successors` leads to, where every
+/// step costs one. Nothing is materialised beyond the nodes reached.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_until(starts, successors, |_| false, record)
+}
+
+/// Like [`bfs`], but stops at the first node for which `is_target` holds,
+/// which is then one of the nearest.
+pub fn bfs_until<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_target: impl FnMut(&N) -> bool,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(record);
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((cur, dist)) = queue.pop_front() {
+        if is_target(&cur) {
+            search.reached = Some(cur);
+            break;
+        }
+        search.expanded += 1;
+        for next in successors(&cur) {
+            match search.distance(&next) {
+                None => {
+                    search.distances.insert(next.clone(), dist + 1);
+                    search.record(&next, &cur, true);
+                    queue.push_back((next, dist + 1));
+                }
+                Some(best) if best == dist + 1 => search.record(&next, &cur, false),
+                Some(_) => {}
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra over the nodes `successors` leads to, where it gives each one
+/// along with the cost of the step.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Ord + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(starts, successors, |_| 0, |_| false, record)
+}
+
+/// Like [`dijkstra`], but stops at the first node for which `is_target`
+/// holds, which is then one of the cheapest to reach. Only the distances to
+/// nodes no further away than it are final, and with steps that cost nothing
+/// some of its equal-cost predecessors may be missed.
+pub fn dijkstra_until<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_target: impl FnMut(&N) -> bool,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Ord + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(starts, successors, |_| 0, is_target, record)
+}
+
+/// A* towards the first node for which `is_goal` holds, expanding nodes in
+/// order of their distance plus `heuristic`. The heuristic must never
+/// overestimate the remaining cost, or the path found may not be the cheapest.
+/// Use [`Search::goal`] for the cost and path, and compare
+/// [`Search::expanded`] with [`dijkstra_until`] to see what guidance saves.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> usize,
+    is_goal: impl FnMut(&N) -> bool,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Ord + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    best_first(starts, successors, heuristic, is_goal, record)
+}
+
+fn best_first<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+    record: Predecessors,
+) -> Search<N>
+where
+    N: Clone + Ord + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new(record);
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        search.distances.insert(start.clone(), 0);
+        heap.push(Reverse((heuristic(&start), 0, start)));
+    }
+
+    while let Some(Reverse((_, dist, cur))) = heap.pop() {
+        if search.distance(&cur).is_some_and(|best| best < dist) {
+            continue;
+        }
+        if is_goal(&cur) {
+            search.reached = Some(cur);
+            break;
+        }
+        search.expanded += 1;
+        for (next, cost) in successors(&cur) {
+            let next_dist = dist + cost;
+            match search.distance(&next) {
+                Some(best) if best < next_dist => {}
+                Some(best) if best == next_dist => search.record(&next, &cur, false),
+                _ => {
+                    search.distances.insert(next.clone(), next_dist);
+                    search.record(&next, &cur, true);
+                    heap.push(Reverse((next_dist + heuristic(&next), next_dist, next)));
+                }
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implicit_test() {
+        // collatz steps walked backwards never run out, so only stopping ends it
+        let step = |n: &u64| {
+            let mut next = vec![n * 2];
+            if n % 6 == 4 && *n > 4 {
+                next.push((n - 1) / 3);
+            }
+            next
+        };
+        let collatz = bfs_until([1], step, |n| *n == 10, Predecessors::One);
+        assert_eq!(collatz.reached, Some(10));
+        assert_eq!(collatz.path_to(&10), Some(vec![1, 2, 4, 8, 16, 5, 10]));
+        assert_eq!(collatz.distance(&10), Some(6));
+
+        // doubling costs more than adding one; stopping at 20 keeps it nearby
+        let costs = |n: &u64| [(n + 1, 1), (n * 2, 3)];
+        let search = dijkstra_until([1], costs, |n| *n == 20, Predecessors::None);
+        assert_eq!(search.distance(&20), Some(10));
+        assert_eq!(search.reached, Some(20));
+        assert!(search.distances.keys().all(|n| *n <= 40));
+        assert_eq!(search.path_to(&20), None);
+
+        // nothing past 20 leads back, and past 10 only single steps get there
+        let cost_left = |n: &u64| match *n {
+            21.. => 1000,
+            11..=20 => (20 - n) as usize,
+            _ => 0,
+        };
+        let guided = astar([1], costs, cost_left, |n| *n == 20, Predecessors::One);
+        let (cost, path) = guided.goal().unwrap();
+        assert_eq!(cost, 10);
+        assert_eq!(path, [1, 2, 3, 4, 5, 10, 20]);
+        assert!(guided.expanded < search.expanded);
+
+        let finite = dijkstra(
+            [0u8],
+            |n| n.checked_add(1).map(|n| (n, 2)),
+            Predecessors::None,
+        );
+        assert_eq!(finite.distance(&255), Some(510));
+        assert_eq!(finite.reached, None);
+
+        // the ways right and down across a 3x3 square
+        let square = |&(row, col): &(u8, u8)| {
+            [(row + 1, col), (row, col + 1)]
+                .into_iter()
+                .filter(|(row, col)| *row < 3 && *col < 3)
+        };
+        let all = bfs([(0, 0)], square, Predecessors::All);
+        let mut paths = all.all_shortest_paths_to(&(2, 2)).unwrap();
+        paths.sort();
+        assert_eq!(paths.len(), 6);
+        assert!(paths
+            .iter()
+            .all(|path| path.len() == 5 && path[0] == (0, 0)));
+        assert_eq!(all.path_to(&(0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+        let weighted = dijkstra([(0, 0)], |n| square(n).map(|n| (n, 2)), Predecessors::All);
+        let mut weighted_paths = weighted.all_shortest_paths_to(&(2, 2)).unwrap();
+        weighted_paths.sort();
+        assert_eq!(weighted_paths, paths);
+
+        // stepping back and forth for free gives endless shortest paths
+        let free = dijkstra([0u8], |&n| [(n ^ 1, 0)], Predecessors::All);
+        assert_eq!(free.path_to(&1), Some(vec![0, 1]));
+        assert_eq!(free.all_shortest_paths_to(&1), None);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{cmp::Reverse, collections::BTreeMap, collections::BinaryHeap};
+
+use super::{implicit::Predecessors, Graph};
+
+/// A graph whose nodes are interned to dense ids, with each node's edges kept
+/// in a `Vec`. Cheaper to search than [`Graph`], which is easier to build.
+#[derive(Debug, Clone)]
+pub struct IndexedGraph<Node> {
+    nodes: Vec<Node>,
+    ids: BTreeMap<Node, usize>,
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<Node: Ord + Copy> Default for IndexedGraph<Node> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Node: Ord + Copy> IndexedGraph<Node> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            ids: BTreeMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// The id of `node`, adding it to the graph if it is new.
+    pub fn intern(&mut self, node: Node) -> usize {
+        *self.ids.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.edges.push(Vec::new());
+            self.nodes.len() - 1
+        })
+    }
+
+    pub fn id(&self, node: &Node) -> Option<usize> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: usize) -> Node {
+        self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn add_edge(&mut self, n1: Node, n2: Node, weight: usize) {
+        let (id1, id2) = (self.intern(n1), self.intern(n2));
+        self.edges[id1].push((id2, weight));
+    }
+
+    /// The `(id, weight)` of every edge leaving `id`.
+    pub fn neighbors(&self, id: usize) -> &[(usize, usize)] {
+        &self.edges[id]
+    }
+
+    /// The distance from `start` to every node, indexed by id, along with the
+    /// ids each node is reached from along a shortest path, as many as `record`
+    /// asks for.
+    pub fn dijkstra(
+        &self,
+        start: usize,
+        record: Predecessors,
+    ) -> (Vec<Option<usize>>, Vec<Vec<usize>>) {
+        let mut distances = vec![None; self.len()];
+        let mut predecessors = vec![Vec::new(); self.len()];
+        let mut heap = BinaryHeap::new();
+        distances[start] = Some(0);
+        heap.push(Reverse((0, start)));
+
+        while let Some(Reverse((dist, cur))) = heap.pop() {
+            if distances[cur].is_some_and(|best| best < dist) {
+                continue;
+            }
+            for &(next, weight) in self.neighbors(cur) {
+                let new_dist = dist + weight;
+                if distances[next].is_none_or(|best| new_dist < best) {
+                    distances[next] = Some(new_dist);
+                    if record != Predecessors::None {
+                        predecessors[next] = vec![cur];
+                    }
+                    heap.push(Reverse((new_dist, next)));
+                } else if record == Predecessors::All
+                    && distances[next] == Some(new_dist)
+                    && !predecessors[next].contains(&cur)
+                {
+                    predecessors[next].push(cur);
+                }
+            }
+        }
+
+        (distances, predecessors)
+    }
+
+    /// Converts distances by id back to distances by node, leaving out the
+    /// nodes that were not reached.
+    pub fn by_node(&self, distances: &[Option<usize>]) -> BTreeMap<Node, usize> {
+        distances
+            .iter()
+            .enumerate()
+            .filter_map(|(id, dist)| dist.map(|dist| (self.node(id), dist)))
+            .collect()
+    }
+}
+
+/// Ids follow the nodes' order, so distances map back to a `BTreeMap` cheaply.
+impl<'a, Node: Ord> From<&'a Graph<Node>> for IndexedGraph<&'a Node> {
+    fn from(graph: &'a Graph<Node>) -> Self {
+        // the keys are already sorted, so most nodes can be looked up among
+        // them; any edge targets that are not keys get merged in after
+        let resolve = |nodes: &[&'a Node], missing: &mut Vec<&'a Node>| {
+            graph
+                .values()
+                .map(|out| {
+                    out.iter()
+                        .filter_map(|(n2, w)| match nodes.binary_search(&n2) {
+                            Ok(id) => Some((id, *w)),
+                            Err(_) => {
+                                missing.push(n2);
+                                None
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut nodes = graph.keys().collect::<Vec<_>>();
+        let mut missing = Vec::new();
+        let mut edges = resolve(&nodes, &mut missing);
+        if !missing.is_empty() {
+            nodes.append(&mut missing);
+            nodes.sort();
+            nodes.dedup();
+            let out = resolve(&nodes, &mut missing);
+            edges = vec![Vec::new(); nodes.len()];
+            for (n1, out) in graph.keys().zip(out) {
+                edges[nodes.binary_search(&n1).expect("a key is a node")] = out;
+            }
+        }
+
+        Self {
+            ids: nodes.iter().enumerate().map(|(id, n)| (*n, id)).collect(),
+            nodes,
+            edges,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{add_edge, dijkstras, search};
+
+    #[test]
+    fn indexed_test() {
+        let mut graph = Graph::new();
+        add_edge(&mut graph, 'a', 'b', 4);
+        add_edge(&mut graph, 'a', 'c', 1);
+        add_edge(&mut graph, 'c', 'b', 2);
+        add_edge(&mut graph, 'b', 'd', 5);
+        add_edge(&mut graph, 'e', 'a', 1);
+
+        let indexed = IndexedGraph::from(&graph);
+        assert_eq!(indexed.len(), 5);
+        let a = indexed.id(&&'a').unwrap();
+        let (distances, predecessors) = indexed.dijkstra(a, Predecessors::One);
+        let [b, c, d] = [&'b', &'c', &'d'].map(|n| indexed.id(&n).unwrap());
+        assert_eq!(distances[d], Some(8));
+        assert_eq!(distances[indexed.id(&&'e').unwrap()], None);
+        assert_eq!((&predecessors[d], &predecessors[b]), (&vec![b], &vec![c]));
+        assert!(predecessors[a].is_empty());
+
+        let expected = BTreeMap::from([(&'a', 0), (&'b', 3), (&'c', 1), (&'d', 8)]);
+        assert_eq!(dijkstras(&graph, &'a'), expected);
+        assert_eq!(dijkstras(&graph, &'z'), BTreeMap::from([(&'z', 0)]));
+
+        // a second way to b that is just as short
+        add_edge(&mut graph, 'a', 'f', 2);
+        add_edge(&mut graph, 'f', 'b', 1);
+        let all = search(&graph, &'a', Predecessors::All);
+        assert_eq!(all.distance(&&'d'), Some(8));
+        let mut prevs = all.all_predecessors[&&'b'].clone();
+        prevs.sort();
+        assert_eq!(prevs, [&'c', &'f']);
+        assert_eq!(all.all_shortest_paths_to(&&'d').unwrap().len(), 2);
+
+        let mut free = Graph::new();
+        add_edge(&mut free, 'a', 'b', 0);
+        add_edge(&mut free, 'b', 'a', 0);
+        let all = search(&free, &'a', Predecessors::All);
+        assert_eq!(all.path_to(&&'b'), Some(vec![&'a', &'b']));
+        assert_eq!(all.all_shortest_paths_to(&&'b'), None);
+    }
+}
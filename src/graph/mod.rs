@@ -1,45 +1,62 @@
 use std::{
-    collections::{btree_map::Entry, BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet},
+    hash::Hash,
     iter,
 };
 
+pub mod implicit;
+mod indexed;
+mod paths;
+pub use indexed::IndexedGraph;
+pub use paths::PathDag;
+
+use implicit::{Predecessors, Search};
+
 pub type Graph<Node> = BTreeMap<Node, BTreeSet<(Node, usize)>>;
 pub type AllPairShortestPaths<Node> = BTreeMap<Node, BTreeMap<Node, usize>>;
 pub type ShortestPaths<Node> = BTreeMap<Node, usize>;
 
+/// Distances from `start` to every node it can reach, found by searching an
+/// [`IndexedGraph`] built from `weighted_graph`.
 pub fn dijkstras<'a, T>(weighted_graph: &'a Graph<T>, start: &'a T) -> ShortestPaths<&'a T>
 where
     T: PartialEq + Eq + Ord + PartialOrd,
 {
-    let mut visited = BTreeSet::new();
-    let mut queue = BTreeSet::new();
-    queue.insert((0usize, start));
-
-    let mut distances = BTreeMap::new();
-    distances.insert(start, 0usize);
+    let mut indexed = IndexedGraph::from(weighted_graph);
+    let start = indexed.intern(start);
+    indexed.by_node(&indexed.dijkstra(start, Predecessors::None).0)
+}
 
-    while let Some((dist, cur)) = queue.pop_first() {
-        if visited.contains(&cur) {
+/// Dijkstra from `start` over an [`IndexedGraph`] built from `graph`, keeping
+/// the predecessors `record` asks for so paths can be read off the result
+/// instead of re-derived with [`all_paths`].
+pub fn search<'a, Node: Ord + Hash>(
+    graph: &'a Graph<Node>,
+    start: &'a Node,
+    record: Predecessors,
+) -> Search<&'a Node> {
+    let mut indexed = IndexedGraph::from(graph);
+    let start = indexed.intern(start);
+    let (distances, predecessors) = indexed.dijkstra(start, record);
+
+    let mut search = Search::new(record);
+    for (id, (dist, prevs)) in distances.into_iter().zip(predecessors).enumerate() {
+        let Some(dist) = dist else {
             continue;
+        };
+        let node = indexed.node(id);
+        search.distances.insert(node, dist);
+        // the first predecessor is the one that beat every earlier way there,
+        // as in `implicit`; the start's are only ties from steps costing nothing
+        if let Some(&prev) = prevs.first().filter(|_| id != start) {
+            search.predecessors.insert(node, indexed.node(prev));
         }
-        visited.insert(cur);
-        for (next, weight) in weighted_graph.get(cur).into_iter().flatten() {
-            let new_dist = dist + *weight;
-            match distances.entry(next) {
-                Entry::Occupied(mut dist) if dist.get() > &new_dist => {
-                    dist.insert(new_dist);
-                    queue.insert((new_dist, next));
-                }
-                Entry::Occupied(_) => {}
-                Entry::Vacant(entry) => {
-                    entry.insert(new_dist);
-                    queue.insert((new_dist, next));
-                }
-            };
+        if record == Predecessors::All && !prevs.is_empty() {
+            let prevs = prevs.into_iter().map(|prev| indexed.node(prev)).collect();
+            search.all_predecessors.insert(node, prevs);
         }
     }
-
-    distances
+    search
 }
 
 pub fn reachable<'a, Node: Ord>(graph: &'a Graph<Node>, start: &'a Node) -> BTreeSet<&'a Node> {
@@ -72,11 +89,13 @@ pub fn nodes<Node: Ord>(graph: &Graph<Node>) -> BTreeSet<&Node> {
 pub fn all_pairs_shortest_paths<Node: Ord + Copy>(
     graph: &Graph<Node>,
 ) -> AllPairShortestPaths<&Node> {
-    let nodes = nodes(graph);
+    let indexed = IndexedGraph::from(graph);
 
-    nodes
-        .into_iter()
-        .map(|n| (n, dijkstras(graph, n)))
+    (0..indexed.len())
+        .map(|id| {
+            let (distances, _) = indexed.dijkstra(id, Predecessors::None);
+            (indexed.node(id), indexed.by_node(&distances))
+        })
         .collect()
 }
 
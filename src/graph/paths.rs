@@ -0,0 +1,232 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use super::implicit::{Predecessors, Search};
+
+/// The part of a search's shortest path DAG that leads to some targets, built
+/// with [`Search::paths_to`]. Everything is worked out from the predecessors
+/// in time linear in the size of this part, without listing any paths.
+#[derive(Debug, Clone)]
+pub struct PathDag<'a, N> {
+    search: &'a Search<N>,
+    targets: Vec<N>,
+    // the number of shortest paths from a start to each node that leads to a
+    // target, saturating at `u128::MAX`
+    counts: HashMap<N, u128>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    /// The shortest path DAG to whichever of `targets` are nearest, if the
+    /// search kept all predecessors and reached any of them. `None` as well if
+    /// steps that cost nothing let the paths go round in circles, as there are
+    /// then endlessly many.
+    pub fn paths_to(&self, targets: impl IntoIterator<Item = N>) -> Option<PathDag<'_, N>> {
+        if self.record != Predecessors::All {
+            return None;
+        }
+        let mut nearest = Vec::new();
+        let mut best = usize::MAX;
+        for target in targets {
+            let Some(dist) = self.distance(&target) else {
+                continue;
+            };
+            if dist < best {
+                best = dist;
+                nearest.clear();
+            }
+            if dist == best && !nearest.contains(&target) {
+                nearest.push(target);
+            }
+        }
+        if nearest.is_empty() {
+            return None;
+        }
+
+        // each node is counted once all of its predecessors are, and meeting a
+        // node again while its predecessors are still being counted means a
+        // cycle
+        let mut counts = HashMap::new();
+        let mut visiting = HashSet::new();
+        let mut stack = nearest
+            .iter()
+            .map(|t| (t.clone(), false))
+            .collect::<Vec<_>>();
+        while let Some((node, ready)) = stack.pop() {
+            if counts.contains_key(&node) {
+                continue;
+            }
+            let Some(prevs) = self.all_predecessors.get(&node) else {
+                counts.insert(node, 1);
+                continue;
+            };
+            if ready {
+                visiting.remove(&node);
+                let count = prevs
+                    .iter()
+                    .map(|prev| counts.get(prev).copied().unwrap_or(0))
+                    .fold(0u128, u128::saturating_add);
+                counts.insert(node, count);
+            } else {
+                if !visiting.insert(node.clone()) {
+                    return None;
+                }
+                stack.push((node, true));
+                stack.extend(
+                    prevs
+                        .iter()
+                        .filter(|prev| !counts.contains_key(*prev))
+                        .map(|prev| (prev.clone(), false)),
+                );
+            }
+        }
+
+        Some(PathDag {
+            search: self,
+            targets: nearest,
+            counts,
+        })
+    }
+}
+
+impl<N: Clone + Eq + Hash> PathDag<'_, N> {
+    /// The number of distinct shortest paths to the targets, saturating at
+    /// `u128::MAX`.
+    pub fn count(&self) -> u128 {
+        self.targets
+            .iter()
+            .map(|t| self.counts[t])
+            .fold(0, u128::saturating_add)
+    }
+
+    /// Every node on at least one shortest path to the targets.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.counts.keys()
+    }
+
+    /// Every step `(from, to)` on at least one shortest path to the targets.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.counts.keys().flat_map(|node| {
+            self.search
+                .all_predecessors
+                .get(node)
+                .into_iter()
+                .flatten()
+                .map(move |prev| (prev, node))
+        })
+    }
+
+    /// The `index`th shortest path, in an order fixed by the search, so every
+    /// index below [`PathDag::count`] gives a different path. An index drawn
+    /// uniformly from that range picks a path uniformly, as long as the count
+    /// has not saturated.
+    pub fn nth_path(&self, mut index: u128) -> Option<Vec<N>> {
+        let mut node = pick(&self.targets, &self.counts, &mut index)?;
+        let mut path = vec![node.clone()];
+        while let Some(prevs) = self.search.all_predecessors.get(node) {
+            node = pick(prevs, &self.counts, &mut index)?;
+            path.push(node.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// A shortest path picked uniformly at random, where `below(n)` must give
+    /// a uniformly random number less than `n`. `None` once the count has
+    /// saturated, as the paths past `u128::MAX` could never be picked.
+    pub fn sample(&self, mut below: impl FnMut(u128) -> u128) -> Option<Vec<N>> {
+        let count = self.count();
+        if count == u128::MAX {
+            return None;
+        }
+        self.nth_path(below(count))
+    }
+}
+
+// the node whose share of the paths `index` falls in, leaving `index` as the
+// position within that share
+fn pick<'a, N: Eq + Hash>(
+    nodes: &'a [N],
+    counts: &HashMap<N, u128>,
+    index: &mut u128,
+) -> Option<&'a N> {
+    for node in nodes {
+        let count = counts.get(node).copied().unwrap_or(0);
+        if *index < count {
+            return Some(node);
+        }
+        *index -= count;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        graph::implicit::{bfs, dijkstra, Predecessors},
+        grid::parse_grid,
+    };
+
+    #[test]
+    fn paths_test() {
+        // the ways right and down across a square, where there are far too
+        // many to list for a big one
+        let square = |size: u8| {
+            move |&(row, col): &(u8, u8)| {
+                [(row + 1, col), (row, col + 1)]
+                    .into_iter()
+                    .filter(move |(row, col)| *row < size && *col < size)
+            }
+        };
+        let small = bfs([(0, 0)], square(3), Predecessors::All);
+        let dag = small.paths_to([(2, 2), (0, 2)]).unwrap();
+        assert_eq!(dag.count(), 1);
+        let dag = small.paths_to([(2, 2)]).unwrap();
+        assert_eq!(dag.count(), 6);
+        assert_eq!(dag.nodes().count(), 9);
+        assert_eq!(dag.edges().count(), 12);
+        let paths = (0..6)
+            .map(|i| dag.nth_path(i).unwrap())
+            .collect::<HashSet<_>>();
+        assert_eq!(paths.len(), 6);
+        assert!(paths
+            .iter()
+            .all(|path| path[0] == (0, 0) && path[4] == (2, 2)));
+        assert_eq!(dag.nth_path(6), None);
+        // a fixed linear congruential generator stands in for a real one
+        let mut state = 1u128;
+        let mut below = |n: u128| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            (state >> 64) % n
+        };
+        for _ in 0..20 {
+            assert!(paths.contains(&dag.sample(&mut below).unwrap()));
+        }
+
+        let big = bfs([(0, 0)], square(60), Predecessors::All);
+        let dag = big.paths_to([(59, 59)]).unwrap();
+        // 118 choose 59
+        assert_eq!(dag.count(), 24_356_699_707_654_619_143_838_606_602_026_720);
+        assert_eq!(dag.nth_path(dag.count() - 1).unwrap().len(), 119);
+        assert_eq!(dag.sample(&mut below).unwrap().len(), 119);
+
+        // 138 choose 69 is too many to count, so there is nothing fair to draw
+        let huge = bfs([(0, 0)], square(70), Predecessors::All);
+        let dag = huge.paths_to([(69, 69)]).unwrap();
+        assert_eq!(dag.count(), u128::MAX);
+        assert_eq!(dag.sample(&mut below), None);
+
+        let partial = bfs([(0, 0)], square(3), Predecessors::One);
+        assert!(partial.paths_to([(2, 2)]).is_none());
+
+        // free steps are fine one way, but back and forth they never end
+        let free = dijkstra([0u8], |&n| (n < 3).then_some((n + 1, 0)), Predecessors::All);
+        assert_eq!(free.paths_to([3]).unwrap().count(), 1);
+        let grid = parse_grid("...\n...\n").unwrap();
+        let free = grid.dijkstra((0, 0), |_, _| Some(0), Predecessors::All);
+        assert!(free.paths_to([(1, 2)]).is_none());
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use super::{Direction, Grid, Index, Neighbourhood};
+
+/// Which cells touch for the purpose of joining a region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Only cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    fn neighbourhood(self) -> Neighbourhood {
+        match self {
+            Self::Four => Neighbourhood::VonNeumann,
+            Self::Eight => Neighbourhood::Moore,
+        }
+    }
+
+    // Holes in a four-connected region may leak out diagonally and the other
+    // way around, otherwise a diagonal gap would both split and join.
+    fn opposite(self) -> Self {
+        match self {
+            Self::Four => Self::Eight,
+            Self::Eight => Self::Four,
+        }
+    }
+}
+
+/// One connected region of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    pub cells: BTreeSet<Index>,
+    connectivity: Connectivity,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The number of cell edges between the region and anything outside it,
+    /// holes included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|pos| Direction::all_directions().map(|dir| dir.apply(*pos)))
+            .filter(|pos| !self.cells.contains(pos))
+            .count()
+    }
+
+    /// The number of straight fence segments around the region, holes
+    /// included. Counted as corners, since every side ends in one.
+    pub fn sides(&self) -> usize {
+        let corners = [
+            (Direction::Up, Direction::Left),
+            (Direction::Up, Direction::Right),
+            (Direction::Down, Direction::Left),
+            (Direction::Down, Direction::Right),
+        ];
+        self.cells
+            .iter()
+            .map(|pos| {
+                corners
+                    .iter()
+                    .filter(|(vertical, horizontal)| {
+                        let a = self.cells.contains(&vertical.apply(*pos));
+                        let b = self.cells.contains(&horizontal.apply(*pos));
+                        let diagonal = self.cells.contains(&horizontal.apply(vertical.apply(*pos)));
+                        // an outer corner, or an inner one
+                        (!a && !b) || (a && b && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// The smallest and largest `(row, col)` in the region.
+    pub fn bounds(&self) -> (Index, Index) {
+        self.cells.iter().fold(
+            ((isize::MAX, isize::MAX), (isize::MIN, isize::MIN)),
+            |(min, max), &(row, col)| {
+                (
+                    (min.0.min(row), min.1.min(col)),
+                    (max.0.max(row), max.1.max(col)),
+                )
+            },
+        )
+    }
+
+    /// The number of separate enclosed areas inside the region that are not
+    /// part of it.
+    pub fn holes(&self) -> usize {
+        let (min, max) = self.bounds();
+        let width = (max.1 - min.1 + 3) as usize;
+        let height = (max.0 - min.0 + 3) as usize;
+        // a one cell border outside the region joins up everything that is not
+        // enclosed
+        let inside = Grid::from_fn(width, height, |(row, col)| {
+            self.cells.contains(&(row + min.0 - 1, col + min.1 - 1))
+        });
+        inside
+            .components(self.connectivity.opposite(), |a, b| a == b)
+            .regions
+            .iter()
+            .filter(|region| !inside[*region.cells.first().expect("regions are not empty")])
+            .count()
+            - 1
+    }
+}
+
+/// Every region of a grid, with the label of the region each cell belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl<V> Grid<V> {
+    /// Labels connected regions, where neighbouring cells join the same region
+    /// when `same` holds for them. Labels count up from 0 in row-major order of
+    /// each region's first cell.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        mut same: impl FnMut(&V, &V) -> bool,
+    ) -> Components {
+        let mut labels = self.map(|_, _| None);
+        let mut regions = Vec::new();
+        let mut queue = VecDeque::new();
+
+        for (start, _) in self.iter_pos() {
+            if labels[start].is_some() {
+                continue;
+            }
+            let label = regions.len();
+            let mut cells = BTreeSet::new();
+            labels[start] = Some(label);
+            queue.push_back(start);
+            while let Some(cur) = queue.pop_front() {
+                cells.insert(cur);
+                for (next, v) in self.neighbors_in(cur, connectivity.neighbourhood()) {
+                    if labels[next].is_none() && same(&self[cur], v) {
+                        labels[next] = Some(label);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            regions.push(Region {
+                label,
+                cells,
+                connectivity,
+            });
+        }
+
+        Components {
+            labels: labels.map(|_, label| label.expect("every cell is labelled")),
+            regions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::parse_grid;
+
+    #[test]
+    fn components_test() {
+        let grid = parse_grid("AAAA\nABBA\nAABA\nAAAA\nC..C\n").unwrap();
+        let components = grid.components(Connectivity::Four, |a, b| a == b);
+        let summary = components
+            .regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter(), r.sides(), r.holes()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            [
+                (13, 24, 10, 1),
+                (3, 8, 6, 0),
+                (1, 4, 4, 0),
+                (2, 6, 4, 0),
+                (1, 4, 4, 0)
+            ]
+        );
+        assert_eq!(components.labels[(2, 2)], 1);
+        assert_eq!(components.regions[0].bounds(), ((0, 0), (3, 3)));
+
+        let diagonal = parse_grid("#.\n.#\n").unwrap();
+        let count = |connectivity| {
+            diagonal
+                .components(connectivity, |a, b| a == b)
+                .regions
+                .len()
+        };
+        assert_eq!(count(Connectivity::Four), 4);
+        assert_eq!(count(Connectivity::Eight), 2);
+    }
+}
@@ -0,0 +1,697 @@
+use std::{collections::BTreeMap, fmt, ops};
+
+use either::Either;
+
+use crate::{point::Point, util};
+
+pub mod components;
+pub mod render;
+pub mod search;
+mod sparse;
+mod transform;
+
+pub use sparse::SparseGrid;
+
+pub type Index = (isize, isize);
+
+/// A rectangular grid stored row-major in one contiguous buffer, addressed by
+/// `(row, col)` positions.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<V> {
+    width: usize,
+    height: usize,
+    cells: Vec<V>,
+}
+
+impl<V> Grid<V> {
+    /// Builds a grid from its cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<V>) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            cells.len() == width * height,
+            "expected {} cells for a {}x{} grid, got {}",
+            width * height,
+            width,
+            height,
+            cells.len()
+        );
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Builds a grid from its rows, rejecting rows of differing lengths.
+    pub fn from_rows<R: IntoIterator<Item = V>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> anyhow::Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for row in rows {
+            let before = cells.len();
+            cells.extend(row);
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    anyhow::bail!("row {} has {} columns, expected {}", height, len, width)
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, value: V) -> Self
+    where
+        V: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, pos: Index) -> Option<usize> {
+        let (row, col) = pos;
+        let row = usize::try_from(row).ok().filter(|row| *row < self.height)?;
+        let col = usize::try_from(col).ok().filter(|col| *col < self.width)?;
+        Some(row * self.width + col)
+    }
+
+    pub fn contains(&self, pos: Index) -> bool {
+        self.offset(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Index) -> Option<&V> {
+        self.offset(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Index) -> Option<&mut V> {
+        self.offset(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, V> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[V]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[V]> + '_ {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn column(&self, col: usize) -> Option<impl Iterator<Item = &V> + '_> {
+        (col < self.width).then(|| self.cells.iter().skip(col).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &V> + '_> + '_ {
+        (0..self.width).map(|col| self.cells.iter().skip(col).step_by(self.width))
+    }
+
+    pub fn iter_pos(&self) -> impl Iterator<Item = (Index, &V)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, v)| (((i / width) as isize, (i % width) as isize), v))
+    }
+
+    /// The in-bounds cardinal neighbours of `pos`.
+    pub fn neighbors(&self, pos: Index) -> impl Iterator<Item = (Index, &V)> + '_ {
+        self.neighbors_in(pos, Neighbourhood::VonNeumann)
+    }
+
+    /// The in-bounds cells of `hood` around `pos`, not including `pos`.
+    pub fn neighbors_in(
+        &self,
+        pos: Index,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Index, &V)> + '_ {
+        hood.around(pos)
+            .filter_map(|pos| self.get(pos).map(|v| (pos, v)))
+    }
+
+    /// Wraps `pos` onto the grid as if its opposite edges were glued together.
+    /// Panics if the grid is empty.
+    pub fn wrap(&self, pos: Index) -> Index {
+        let bounds = Point(self.height as isize, self.width as isize);
+        Point::from(pos).wrap(bounds).into()
+    }
+
+    /// A view of the grid as a torus, where stepping off one edge comes back
+    /// in on the opposite one.
+    pub fn wrapping(&self) -> Wrapping<'_, V> {
+        Wrapping { grid: self }
+    }
+
+    /// A view of the grid repeated infinitely in every direction.
+    pub fn tiled(&self) -> Tiled<'_, V> {
+        Tiled { grid: self }
+    }
+
+    pub fn map<V2, F>(&self, mut f: F) -> Grid<V2>
+    where
+        F: FnMut(Index, &V) -> V2,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter_pos().map(|(pos, v)| f(pos, v)).collect(),
+        }
+    }
+
+    pub fn map_result<V2, E, F>(&self, mut f: F) -> Result<Grid<V2>, E>
+    where
+        F: FnMut(Index, &V) -> Result<V2, E>,
+    {
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .iter_pos()
+                .map(|(pos, v)| f(pos, v))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// A grid of the same shape filled with `V2::default()`.
+    pub fn copy_default<V2: Default>(&self) -> Grid<V2> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(|_| V2::default()).collect(),
+        }
+    }
+}
+
+impl<V> ops::Index<Index> for Grid<V> {
+    type Output = V;
+
+    fn index(&self, pos: Index) -> &V {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<V> ops::IndexMut<Index> for Grid<V> {
+    fn index_mut(&mut self, pos: Index) -> &mut V {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+/// See [`Grid::wrapping`]. Positions are always wrapped back onto the grid.
+pub struct Wrapping<'a, V> {
+    grid: &'a Grid<V>,
+}
+
+impl<'a, V> Wrapping<'a, V> {
+    /// Only `None` when the grid is empty.
+    pub fn get(&self, pos: Index) -> Option<&'a V> {
+        (!self.grid.cells.is_empty()).then(|| &self.grid[self.grid.wrap(pos)])
+    }
+
+    /// Only `None` when the grid is empty.
+    pub fn step(&self, pos: Index, dir: impl Into<Direction8>) -> Option<Index> {
+        (!self.grid.cells.is_empty()).then(|| self.grid.wrap(dir.into().apply(pos)))
+    }
+
+    pub fn neighbors(&self, pos: Index) -> impl Iterator<Item = (Index, &'a V)> + '_ {
+        self.neighbors_in(pos, Neighbourhood::VonNeumann)
+    }
+
+    pub fn neighbors_in(
+        &self,
+        pos: Index,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Index, &'a V)> + '_ {
+        // nothing is wrapped until `get` has ruled out an empty grid
+        hood.around(pos)
+            .filter_map(|pos| self.get(pos).map(|v| (self.grid.wrap(pos), v)))
+    }
+}
+
+/// See [`Grid::tiled`]. Positions are left as they are, so every copy of a
+/// cell has its own position.
+pub struct Tiled<'a, V> {
+    grid: &'a Grid<V>,
+}
+
+impl<'a, V> Tiled<'a, V> {
+    /// Only `None` when the grid is empty.
+    pub fn get(&self, pos: Index) -> Option<&'a V> {
+        self.grid.wrapping().get(pos)
+    }
+
+    /// Which copy of the grid `pos` falls in, with `(0, 0)` being the original.
+    /// Only `None` when the grid is empty.
+    pub fn tile(&self, pos: Index) -> Option<Point> {
+        (!self.grid.cells.is_empty()).then(|| {
+            Point(
+                pos.0.div_euclid(self.grid.height as isize),
+                pos.1.div_euclid(self.grid.width as isize),
+            )
+        })
+    }
+
+    pub fn neighbors(&self, pos: Index) -> impl Iterator<Item = (Index, &'a V)> + '_ {
+        self.neighbors_in(pos, Neighbourhood::VonNeumann)
+    }
+
+    pub fn neighbors_in(
+        &self,
+        pos: Index,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Index, &'a V)> + '_ {
+        hood.around(pos)
+            .filter_map(|pos| self.get(pos).map(|v| (pos, v)))
+    }
+}
+
+pub fn read_grid(reader: &mut dyn std::io::Read) -> anyhow::Result<Grid<char>> {
+    let mut lines = util::read_lines(reader)?;
+    let grid = Grid::from_rows(lines.by_ref().map(|line| line.chars().collect::<Vec<_>>()))?;
+    lines.error()?;
+    Ok(grid)
+}
+
+pub fn parse_grid(input: &str) -> anyhow::Result<Grid<char>> {
+    Grid::from_rows(input.lines().map(|line| line.chars()))
+}
+
+/// Where each marker character was found by [`parse_typed`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: BTreeMap<char, Vec<Index>>,
+}
+
+impl Markers {
+    /// Every position of `marker` in row-major order.
+    pub fn all(&self, marker: char) -> &[Index] {
+        self.positions.get(&marker).map_or(&[], Vec::as_slice)
+    }
+
+    /// The position of a marker that should appear exactly once.
+    pub fn one(&self, marker: char) -> anyhow::Result<Index> {
+        match self.all(marker) {
+            [pos] => Ok(*pos),
+            [] => anyhow::bail!("could not find '{}' in the grid", marker),
+            found => anyhow::bail!(
+                "expected one '{}' in the grid, found {}",
+                marker,
+                found.len()
+            ),
+        }
+    }
+}
+
+/// Parses a character map into typed cells, recording where each character in
+/// `markers` appears. Marker cells are converted like any other.
+pub fn parse_typed<T>(input: &str, markers: &str) -> anyhow::Result<(Grid<T>, Markers)>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    let mut found = Markers::default();
+    let mut rows = Vec::new();
+    for (row, line) in input.lines().enumerate() {
+        let cells = line
+            .chars()
+            .enumerate()
+            .map(|(col, chr)| {
+                let pos = (row as isize, col as isize);
+                if markers.contains(chr) {
+                    found.positions.entry(chr).or_default().push(pos);
+                }
+                T::try_from(chr).map_err(|e| anyhow::anyhow!("row {}, col {}: {}", row, col, e))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        rows.push(cells);
+    }
+
+    Ok((Grid::from_rows(rows)?, found))
+}
+
+pub fn read_typed<T>(
+    reader: &mut dyn std::io::Read,
+    markers: &str,
+) -> anyhow::Result<(Grid<T>, Markers)>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    parse_typed(&input, markers)
+}
+
+// Tuple versions of the `Point` operators, kept while callers migrate.
+
+pub fn vec_add(v: (isize, isize), v2: (isize, isize)) -> (isize, isize) {
+    (Point::from(v) + Point::from(v2)).into()
+}
+
+pub fn vec_sub(v: (isize, isize), v2: (isize, isize)) -> (isize, isize) {
+    (Point::from(v) - Point::from(v2)).into()
+}
+
+pub fn scale(v: (isize, isize), scale: isize) -> (isize, isize) {
+    (Point::from(v) * scale).into()
+}
+
+pub fn reduce_vec(v: (isize, isize)) -> (isize, isize) {
+    Point::from(v).reduce().into()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum Direction {
+    Up = 1 << 0,
+    Down = 1 << 1,
+    Left = 1 << 2,
+    Right = 1 << 3,
+}
+
+impl Direction {
+    pub fn all_directions() -> [Self; 4] {
+        [Self::Up, Self::Down, Self::Left, Self::Right]
+    }
+
+    pub fn all_diagonals() -> [[Self; 2]; 4] {
+        [
+            [Self::Up, Self::Left],
+            [Self::Up, Self::Right],
+            [Self::Down, Self::Left],
+            [Self::Down, Self::Right],
+        ]
+    }
+
+    pub fn horizontal(self) -> [Self; 2] {
+        [Self::Left, Self::Right]
+    }
+
+    pub fn vertical(self) -> [Self; 2] {
+        [Self::Up, Self::Down]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        self.horizontal().contains(&self)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        self.vertical().contains(&self)
+    }
+
+    pub fn adjacent(self) -> [Self; 2] {
+        if self.is_horizontal() {
+            self.vertical()
+        } else {
+            self.horizontal()
+        }
+    }
+
+    pub fn invert(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    pub fn rotate_90_right(&self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+            Self::Right => Self::Down,
+        }
+    }
+
+    pub fn apply(self, cur: (isize, isize)) -> (isize, isize) {
+        let delta = match self {
+            Self::Up => (-1, 0),
+            Self::Right => (0, 1),
+            Self::Left => (0, -1),
+            Self::Down => (1, 0),
+        };
+
+        (cur.0 + delta.0, cur.1 + delta.1)
+    }
+
+    pub fn from_delta(delta: (isize, isize)) -> Option<Self> {
+        let res = match delta {
+            (-1, 0) => Self::Up,
+            (0, 1) => Self::Right,
+            (0, -1) => Self::Left,
+            (1, 0) => Self::Down,
+            _ => return None,
+        };
+
+        Some(res)
+    }
+
+    pub fn apply_inverse(self, cur: (isize, isize)) -> (isize, isize) {
+        self.invert().apply(cur)
+    }
+}
+
+/// The eight compass directions, in clockwise order starting from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub fn all() -> [Self; 8] {
+        [
+            Self::Up,
+            Self::UpRight,
+            Self::Right,
+            Self::DownRight,
+            Self::Down,
+            Self::DownLeft,
+            Self::Left,
+            Self::UpLeft,
+        ]
+    }
+
+    pub fn diagonals() -> [Self; 4] {
+        [Self::UpRight, Self::DownRight, Self::DownLeft, Self::UpLeft]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        Self::diagonals().contains(&self)
+    }
+
+    /// The `(row, col)` offset of one step in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Self::Up => Point(-1, 0),
+            Self::UpRight => Point(-1, 1),
+            Self::Right => Point(0, 1),
+            Self::DownRight => Point(1, 1),
+            Self::Down => Point(1, 0),
+            Self::DownLeft => Point(1, -1),
+            Self::Left => Point(0, -1),
+            Self::UpLeft => Point(-1, -1),
+        }
+    }
+
+    pub fn apply(self, cur: Index) -> Index {
+        (Point::from(cur) + self.delta()).into()
+    }
+
+    pub fn invert(self) -> Self {
+        Self::all()[(self as usize + 4) % 8]
+    }
+
+    pub fn rotate_45_right(self) -> Self {
+        Self::all()[(self as usize + 1) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Self::Up,
+            Direction::Down => Self::Down,
+            Direction::Left => Self::Left,
+            Direction::Right => Self::Right,
+        }
+    }
+}
+
+/// Which cells count as neighbours of a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// The four cardinal neighbours.
+    VonNeumann,
+    /// The eight cardinal and diagonal neighbours.
+    Moore,
+    /// Every cell within the given manhattan distance.
+    Diamond(usize),
+}
+
+impl Neighbourhood {
+    /// Offsets of every neighbour in row-major order, excluding the origin.
+    /// [`Neighbourhood::VonNeumann`] keeps the order of
+    /// [`Direction::all_directions`] instead, as grid neighbours always have.
+    pub fn offsets(self) -> impl Iterator<Item = Point> {
+        let (radius, chebyshev) = match self {
+            Self::VonNeumann => {
+                return Either::Left(
+                    Direction::all_directions()
+                        .into_iter()
+                        .map(|dir| Point::from(dir.apply((0, 0)))),
+                )
+            }
+            Self::Moore => (1, true),
+            Self::Diamond(radius) => (radius, false),
+        };
+        let r = radius as isize;
+        let offsets = (-r..=r)
+            .flat_map(move |row| (-r..=r).map(move |col| Point(row, col)))
+            .filter(move |p| {
+                let distance = if chebyshev {
+                    p.chebyshev(Point::ZERO)
+                } else {
+                    p.manhattan(Point::ZERO)
+                };
+                (1..=radius).contains(&distance)
+            });
+        Either::Right(offsets)
+    }
+
+    /// Positions of every neighbour of `pos`, ignoring any bounds.
+    pub fn around(self, pos: Index) -> impl Iterator<Item = Index> {
+        self.offsets()
+            .map(move |delta| (Point::from(pos) + delta).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_test() {
+        let grid = parse_grid("abc\ndef\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.column(1).unwrap().copied().collect::<String>(), "be");
+
+        let err = parse_grid("abc\nde\n").unwrap_err();
+        assert_eq!(err.to_string(), "row 1 has 2 columns, expected 3");
+    }
+
+    #[test]
+    fn parse_typed_test() {
+        #[derive(Debug, PartialEq)]
+        struct Wall(bool);
+
+        impl TryFrom<char> for Wall {
+            type Error = anyhow::Error;
+
+            fn try_from(chr: char) -> anyhow::Result<Self> {
+                match chr {
+                    '#' => Ok(Wall(true)),
+                    '.' | 'S' | 'E' => Ok(Wall(false)),
+                    chr => anyhow::bail!("unexpected '{}'", chr),
+                }
+            }
+        }
+
+        let (grid, markers) = parse_typed::<Wall>("#S.\n.E#\n", "SE").unwrap();
+        assert_eq!(grid[(1, 2)], Wall(true));
+        assert_eq!(markers.one('S').unwrap(), (0, 1));
+        assert_eq!(markers.all('E'), [(1, 1)]);
+        assert!(markers.one('X').is_err());
+
+        let err = parse_typed::<Wall>("#S.\n.?#\n", "").unwrap_err();
+        assert_eq!(err.to_string(), "row 1, col 1: unexpected '?'");
+    }
+
+    #[test]
+    fn neighbourhood_test() {
+        let grid = parse_grid("abc\ndef\nghi\n").unwrap();
+        let around = |pos, hood| {
+            grid.neighbors_in(pos, hood)
+                .map(|(_, c)| *c)
+                .collect::<String>()
+        };
+        assert_eq!(around((0, 0), Neighbourhood::VonNeumann), "db");
+        assert_eq!(around((1, 1), Neighbourhood::Moore), "abcdfghi");
+        assert_eq!(around((0, 0), Neighbourhood::Diamond(2)), "bcdeg");
+        assert_eq!(Neighbourhood::Diamond(3).offsets().count(), 24);
+        assert_eq!(Direction8::UpLeft.invert(), Direction8::DownRight);
+        assert_eq!(Direction8::UpLeft.rotate_45_right(), Direction8::Up);
+    }
+
+    #[test]
+    fn wrapping_test() {
+        let grid = parse_grid("abc\ndef\n").unwrap();
+        let wrapping = grid.wrapping();
+        assert_eq!(wrapping.get((-1, 3)), Some(&'d'));
+        assert_eq!(wrapping.step((0, 0), Direction::Left), Some((0, 2)));
+        let around = wrapping.neighbors((0, 0)).collect::<Vec<_>>();
+        assert_eq!(
+            around,
+            [
+                ((1, 0), &'d'),
+                ((1, 0), &'d'),
+                ((0, 2), &'c'),
+                ((0, 1), &'b')
+            ]
+        );
+
+        let tiled = grid.tiled();
+        assert_eq!(tiled.get((5, -1)), Some(&'f'));
+        assert_eq!(tiled.tile((5, -1)), Some(Point(2, -1)));
+        assert_eq!(
+            tiled
+                .neighbors((0, 0))
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            [(-1, 0), (1, 0), (0, -1), (0, 1)]
+        );
+
+        for empty in ["", "\n\n"].map(|input| parse_grid(input).unwrap()) {
+            let wrapping = empty.wrapping();
+            assert_eq!(wrapping.get((1, 1)), None);
+            assert_eq!(wrapping.step((0, 0), Direction::Up), None);
+            assert_eq!(wrapping.neighbors((0, 0)).count(), 0);
+            assert_eq!(empty.tiled().neighbors((0, 0)).count(), 0);
+            assert_eq!(empty.tiled().tile((1, 1)), None);
+        }
+    }
+}
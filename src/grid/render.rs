@@ -0,0 +1,232 @@
+use std::{borrow::Cow, collections::HashMap, fmt};
+
+use super::{Direction, Grid, Index, SparseGrid};
+
+/// The eight basic ANSI terminal colours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Colour {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    fn code(self) -> u8 {
+        30 + self as u8
+    }
+}
+
+/// How a highlighted cell is drawn. Unset fields fall back to the cell's own.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub glyph: Option<char>,
+    pub colour: Option<Colour>,
+}
+
+impl Style {
+    pub fn glyph(glyph: char) -> Self {
+        Self {
+            glyph: Some(glyph),
+            colour: None,
+        }
+    }
+
+    pub fn colour(colour: Colour) -> Self {
+        Self {
+            glyph: None,
+            colour: Some(colour),
+        }
+    }
+
+    pub fn with_colour(self, colour: Colour) -> Self {
+        Self {
+            colour: Some(colour),
+            ..self
+        }
+    }
+
+    fn over(self, below: Self) -> Self {
+        Self {
+            glyph: self.glyph.or(below.glyph),
+            colour: self.colour.or(below.colour),
+        }
+    }
+}
+
+/// A grid cell that knows how to draw itself.
+pub trait Cell {
+    fn glyph(&self) -> char;
+
+    fn colour(&self) -> Option<Colour> {
+        None
+    }
+}
+
+impl Cell for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl Cell for bool {
+    fn glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Unset cells of a sparse grid are drawn as `.`.
+impl<V: Cell> Cell for Option<V> {
+    fn glyph(&self) -> char {
+        self.as_ref().map_or('.', Cell::glyph)
+    }
+
+    fn colour(&self) -> Option<Colour> {
+        self.as_ref().and_then(Cell::colour)
+    }
+}
+
+/// A grid drawn one line per row, built with [`Grid::render`] or
+/// [`SparseGrid::render`]. Formatting it does the drawing, so it costs nothing
+/// when passed to a log macro whose level is off.
+pub struct Render<'a, V: Clone> {
+    grid: Cow<'a, Grid<V>>,
+    origin: Index,
+    overlay: HashMap<Index, Style>,
+    colour: bool,
+}
+
+impl<'a, V: Cell + Clone> Render<'a, V> {
+    /// Draws `style` over every position, on top of earlier highlights.
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Index>, style: Style) -> Self {
+        for pos in positions {
+            let below = self.overlay.get(&pos).copied().unwrap_or_default();
+            self.overlay.insert(pos, style.over(below));
+        }
+        self
+    }
+
+    /// Draws an arrow on each step of `path` pointing to the next one. Steps
+    /// that are not to a cardinal neighbour are drawn as `*`, and the final
+    /// position keeps its own glyph.
+    pub fn path(mut self, path: &[Index], colour: Option<Colour>) -> Self {
+        for step in path.windows(2) {
+            let delta = (step[1].0 - step[0].0, step[1].1 - step[0].1);
+            let glyph = match Direction::from_delta(delta) {
+                Some(Direction::Up) => '^',
+                Some(Direction::Down) => 'v',
+                Some(Direction::Left) => '<',
+                Some(Direction::Right) => '>',
+                None => '*',
+            };
+            self = self.highlight(
+                [step[0]],
+                Style {
+                    glyph: Some(glyph),
+                    colour,
+                },
+            );
+        }
+        if let (Some(&last), Some(colour)) = (path.last(), colour) {
+            self = self.highlight([last], Style::colour(colour));
+        }
+        self
+    }
+
+    /// Whether to emit ANSI colour codes; off by default.
+    pub fn colour(self, colour: bool) -> Self {
+        Self { colour, ..self }
+    }
+}
+
+impl<V: Cell + Clone> fmt::Display for Render<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (row, cells) in self.grid.rows().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                let pos = (self.origin.0 + row as isize, self.origin.1 + col as isize);
+                let own = Style {
+                    glyph: Some(cell.glyph()),
+                    colour: cell.colour(),
+                };
+                let style = match self.overlay.get(&pos) {
+                    Some(style) => style.over(own),
+                    None => own,
+                };
+                let glyph = style.glyph.unwrap_or(' ');
+                match style.colour {
+                    Some(colour) if self.colour => {
+                        write!(f, "\x1b[{}m{}\x1b[0m", colour.code(), glyph)?
+                    }
+                    _ => write!(f, "{}", glyph)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<V: Cell + Clone> Grid<V> {
+    pub fn render(&self) -> Render<'_, V> {
+        Render {
+            grid: Cow::Borrowed(self),
+            origin: (0, 0),
+            overlay: HashMap::new(),
+            colour: false,
+        }
+    }
+}
+
+impl<V: Cell + Clone> SparseGrid<V> {
+    /// Draws the bounding box of the set cells. Overlay positions are in the
+    /// sparse grid's own coordinates.
+    pub fn render(&self) -> Render<'static, Option<V>> {
+        let (origin, grid) = self
+            .iter_pos()
+            .map(|(pos, v)| (pos, Some(v.clone())))
+            .collect::<SparseGrid<_>>()
+            .to_dense(None);
+        Render {
+            grid: Cow::Owned(grid),
+            origin,
+            overlay: HashMap::new(),
+            colour: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::parse_grid;
+
+    #[test]
+    fn render_test() {
+        let grid = parse_grid("#..\n#.#\n").unwrap();
+        let render = grid
+            .render()
+            .path(&[(0, 1), (1, 1), (0, 1), (0, 2)], None)
+            .highlight([(1, 2)], Style::glyph('O'));
+        assert_eq!(render.to_string(), "#>.\n#^O\n");
+
+        let coloured = grid
+            .render()
+            .highlight([(0, 0)], Style::colour(Colour::Red));
+        assert!(coloured
+            .colour(true)
+            .to_string()
+            .starts_with("\x1b[31m#\x1b[0m.."));
+
+        let sparse: SparseGrid<char> = [((-1, -1), 'a'), ((0, 1), 'b')].into_iter().collect();
+        let render = sparse.render().highlight([(-1, 0)], Style::glyph('*'));
+        assert_eq!(render.to_string(), "a*.\n..b\n");
+    }
+}
@@ -0,0 +1,135 @@
+use super::{Grid, Index};
+use crate::{graph::implicit, point::Point};
+
+// searches whose nodes carry more than a position, such as a facing
+// `Direction`, take their successors over `(Index, S)` directly
+pub use crate::graph::implicit::{
+    astar, bfs, bfs_until, dijkstra, dijkstra_until, Predecessors, Search,
+};
+
+impl<V> Grid<V> {
+    /// Breadth first search over cardinal neighbours, stepping from one cell to
+    /// the next only where `passable` holds for the pair.
+    pub fn bfs(
+        &self,
+        start: Index,
+        mut passable: impl FnMut(&V, &V) -> bool,
+        record: Predecessors,
+    ) -> Search<Index> {
+        let start = self.contains(start).then_some(start);
+        implicit::bfs(
+            start,
+            |&pos| {
+                self.neighbors(pos)
+                    .filter(|(_, v)| passable(&self[pos], v))
+                    .map(|(next, _)| next)
+                    .collect::<Vec<_>>()
+            },
+            record,
+        )
+    }
+
+    /// Dijkstra over cardinal neighbours, where `cost` gives the price of a
+    /// step between two cells or `None` if it cannot be taken.
+    pub fn dijkstra(
+        &self,
+        start: Index,
+        mut cost: impl FnMut(&V, &V) -> Option<usize>,
+        record: Predecessors,
+    ) -> Search<Index> {
+        let start = self.contains(start).then_some(start);
+        implicit::dijkstra(start, |&pos| self.steps(pos, &mut cost), record)
+    }
+
+    /// A* from `start` to `goal` over cardinal neighbours, guided by the
+    /// manhattan distance. Every step must cost at least one.
+    pub fn astar(
+        &self,
+        start: Index,
+        goal: Index,
+        mut cost: impl FnMut(&V, &V) -> Option<usize>,
+        record: Predecessors,
+    ) -> Search<Index> {
+        let start = self.contains(start).then_some(start);
+        implicit::astar(
+            start,
+            |&pos| self.steps(pos, &mut cost),
+            |&pos| Point::from(pos).manhattan(goal.into()),
+            |&pos| pos == goal,
+            record,
+        )
+    }
+
+    fn steps(
+        &self,
+        pos: Index,
+        cost: &mut impl FnMut(&V, &V) -> Option<usize>,
+    ) -> Vec<(Index, usize)> {
+        self.neighbors(pos)
+            .filter_map(|(next, v)| cost(&self[pos], v).map(|cost| (next, cost)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{parse_grid, Direction};
+
+    #[test]
+    fn search_test() {
+        let grid = parse_grid("..#.\n#...\n..#.\n").unwrap();
+        let open = |_: &char, to: &char| *to != '#';
+        let cost = |_: &char, to: &char| (*to != '#').then_some(1);
+
+        let bfs = grid.bfs((0, 0), open, Predecessors::One);
+        assert_eq!(bfs.distance(&(2, 3)), Some(5));
+        assert_eq!(bfs.distance(&(0, 2)), None);
+        let path = bfs.path_to(&(2, 3)).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), ((0, 0), (2, 3)));
+
+        assert_eq!(
+            grid.dijkstra((0, 0), cost, Predecessors::None).distances,
+            bfs.distances
+        );
+        assert_eq!(
+            grid.astar((0, 0), (2, 3), cost, Predecessors::None)
+                .distance(&(2, 3)),
+            Some(5)
+        );
+
+        // a start off the grid reaches nothing, whichever search is used
+        let outside = (3, 0);
+        assert!(grid
+            .bfs(outside, open, Predecessors::None)
+            .distances
+            .is_empty());
+        assert!(grid
+            .dijkstra(outside, cost, Predecessors::None)
+            .distances
+            .is_empty());
+        assert!(grid
+            .astar(outside, (2, 3), cost, Predecessors::None)
+            .distances
+            .is_empty());
+
+        // turning costs extra, so the search keeps going straight where it can
+        let turning = dijkstra(
+            [((0, 0), Direction::Right)],
+            |&(pos, dir)| {
+                let mut next = vec![];
+                for turn in dir.adjacent() {
+                    next.push(((pos, turn), 10));
+                }
+                let ahead = dir.apply(pos);
+                if grid.get(ahead).is_some_and(|c| *c != '#') {
+                    next.push(((ahead, dir), 1));
+                }
+                next
+            },
+            Predecessors::None,
+        );
+        assert_eq!(turning.distance(&((2, 3), Direction::Down)), Some(35));
+    }
+}
@@ -0,0 +1,171 @@
+use std::{collections::HashMap, ops};
+
+use super::{Grid, Index, Neighbourhood};
+
+/// A grid that only stores the cells that have been set, so positions can be
+/// negative and the grid grows in any direction as cells are inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<V> {
+    cells: HashMap<Index, V>,
+}
+
+impl<V> Default for SparseGrid<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V> SparseGrid<V> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Keeps the cells of a dense grid for which `keep` holds, at the same
+    /// positions.
+    pub fn from_dense(grid: &Grid<V>, mut keep: impl FnMut(&V) -> bool) -> Self
+    where
+        V: Clone,
+    {
+        grid.iter_pos()
+            .filter(|(_, v)| keep(v))
+            .map(|(pos, v)| (pos, v.clone()))
+            .collect()
+    }
+
+    /// Copies the cells into a dense grid covering the bounding box, filling
+    /// the gaps with `fill`. Returns the position of the dense grid's `(0, 0)`
+    /// alongside it.
+    pub fn to_dense(&self, fill: V) -> (Index, Grid<V>)
+    where
+        V: Clone,
+    {
+        let Some((min, max)) = self.bounds() else {
+            return ((0, 0), Grid::filled(0, 0, fill));
+        };
+        let width = (max.1 - min.1 + 1) as usize;
+        let height = (max.0 - min.0 + 1) as usize;
+        let mut grid = Grid::filled(width, height, fill);
+        for (pos, v) in self.iter_pos() {
+            grid[(pos.0 - min.0, pos.1 - min.1)] = v.clone();
+        }
+        (min, grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Index) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Index) -> Option<&V> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Index) -> Option<&mut V> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Sets a cell, returning what was there before.
+    pub fn insert(&mut self, pos: Index, value: V) -> Option<V> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Index) -> Option<V> {
+        self.cells.remove(&pos)
+    }
+
+    /// The cell at `pos`, inserting `V::default()` first if it is unset.
+    pub fn entry(&mut self, pos: Index) -> &mut V
+    where
+        V: Default,
+    {
+        self.cells.entry(pos).or_default()
+    }
+
+    /// Set cells in no particular order.
+    pub fn iter_pos(&self) -> impl Iterator<Item = (Index, &V)> + '_ {
+        self.cells.iter().map(|(pos, v)| (*pos, v))
+    }
+
+    /// The set cardinal neighbours of `pos`.
+    pub fn neighbors(&self, pos: Index) -> impl Iterator<Item = (Index, &V)> + '_ {
+        self.neighbors_in(pos, Neighbourhood::VonNeumann)
+    }
+
+    /// The set cells of `hood` around `pos`, not including `pos`.
+    pub fn neighbors_in(
+        &self,
+        pos: Index,
+        hood: Neighbourhood,
+    ) -> impl Iterator<Item = (Index, &V)> + '_ {
+        hood.around(pos)
+            .filter_map(|pos| self.get(pos).map(|v| (pos, v)))
+    }
+
+    /// The smallest and largest `(row, col)` of any set cell, or `None` when
+    /// the grid is empty.
+    pub fn bounds(&self) -> Option<(Index, Index)> {
+        self.cells.keys().fold(None, |acc, &(row, col)| match acc {
+            None => Some(((row, col), (row, col))),
+            Some((min, max)) => Some((
+                (min.0.min(row), min.1.min(col)),
+                (max.0.max(row), max.1.max(col)),
+            )),
+        })
+    }
+}
+
+impl<V> FromIterator<(Index, V)> for SparseGrid<V> {
+    fn from_iter<I: IntoIterator<Item = (Index, V)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<V> Extend<(Index, V)> for SparseGrid<V> {
+    fn extend<I: IntoIterator<Item = (Index, V)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+impl<V> ops::Index<Index> for SparseGrid<V> {
+    type Output = V;
+
+    fn index(&self, pos: Index) -> &V {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is not set in the sparse grid", pos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::parse_grid;
+
+    #[test]
+    fn sparse_test() {
+        let mut sparse = SparseGrid::new();
+        sparse.insert((-1, 2), 'a');
+        sparse.insert((1, -1), 'b');
+        *sparse.entry((0, 0)) = 'c';
+        assert_eq!(sparse.bounds(), Some(((-1, -1), (1, 2))));
+        assert_eq!(
+            sparse.neighbors((0, -1)).collect::<Vec<_>>(),
+            [((1, -1), &'b'), ((0, 0), &'c')]
+        );
+
+        let (origin, dense) = sparse.to_dense('.');
+        assert_eq!(origin, (-1, -1));
+        assert_eq!(dense, parse_grid("...a\n.c..\nb...\n").unwrap());
+        assert_eq!(SparseGrid::from_dense(&dense, |c| *c != '.').len(), 3);
+    }
+}
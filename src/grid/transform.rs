@@ -0,0 +1,114 @@
+use super::{Grid, Index};
+
+impl<V> Grid<V> {
+    /// Builds a grid by calling `f` on every position in row-major order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Index) -> V) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row as isize, col as isize)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<V: Clone> Grid<V> {
+    /// Swaps rows and columns, mirroring along the main diagonal.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, row)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let last = self.height as isize - 1;
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(last - col, row)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn anticlockwise.
+    pub fn rotate_left(&self) -> Self {
+        let last = self.width as isize - 1;
+        Self::from_fn(self.height, self.width, |(row, col)| {
+            self[(col, last - row)].clone()
+        })
+    }
+
+    /// Mirrors left to right, reversing every row.
+    pub fn flip_horizontal(&self) -> Self {
+        let last = self.width as isize - 1;
+        Self::from_fn(self.width, self.height, |(row, col)| {
+            self[(row, last - col)].clone()
+        })
+    }
+
+    /// Mirrors top to bottom, reversing the order of the rows.
+    pub fn flip_vertical(&self) -> Self {
+        let last = self.height as isize - 1;
+        Self::from_fn(self.width, self.height, |(row, col)| {
+            self[(last - row, col)].clone()
+        })
+    }
+
+    /// The `width` by `height` sub-grid whose top left corner is at `corner`,
+    /// or `None` if it does not fit inside the grid.
+    pub fn crop(&self, corner: Index, width: usize, height: usize) -> Option<Self> {
+        let fits =
+            |start: isize, len: usize, bound: usize| start >= 0 && start as usize + len <= bound;
+        if !fits(corner.0, height, self.height) || !fits(corner.1, width, self.width) {
+            return None;
+        }
+        Some(Self::from_fn(width, height, |(row, col)| {
+            self[(corner.0 + row, corner.1 + col)].clone()
+        }))
+    }
+
+    /// Surrounds the grid with a border `size` cells thick filled with
+    /// `value`, so the old `(0, 0)` moves to `(size, size)`.
+    pub fn pad(&self, size: usize, value: V) -> Self {
+        let offset = size as isize;
+        Self::from_fn(
+            self.width + 2 * size,
+            self.height + 2 * size,
+            |(row, col)| {
+                self.get((row - offset, col - offset))
+                    .unwrap_or(&value)
+                    .clone()
+            },
+        )
+    }
+
+    /// Repeats the grid `across` times to the right and `down` times below.
+    pub fn tile(&self, across: usize, down: usize) -> Self {
+        Self::from_fn(self.width * across, self.height * down, |pos| {
+            self[self.wrap(pos)].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::parse_grid;
+
+    #[test]
+    fn transform_test() {
+        let grid = parse_grid("abc\ndef\n").unwrap();
+        let expect = |s: &str| parse_grid(s).unwrap();
+
+        assert_eq!(grid.transpose(), expect("ad\nbe\ncf\n"));
+        assert_eq!(grid.rotate_right(), expect("da\neb\nfc\n"));
+        assert_eq!(grid.rotate_left(), expect("cf\nbe\nad\n"));
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.flip_horizontal(), expect("cba\nfed\n"));
+        assert_eq!(grid.flip_vertical(), expect("def\nabc\n"));
+        assert_eq!(grid.crop((1, 1), 2, 1), Some(expect("ef\n")));
+        assert_eq!(grid.crop((1, 2), 2, 1), None);
+        assert_eq!(grid.pad(1, '.'), expect(".....\n.abc.\n.def.\n.....\n"));
+        assert_eq!(grid.tile(2, 2), expect("abcabc\ndefdef\nabcabc\ndefdef\n"));
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+
+use crate::{answers::Answers, util};
+
+/// Where the published examples live, one `dayNN/` directory per day holding
+/// `exampleN.txt` inputs next to their `exampleN.answers`.
+pub const EXAMPLES_DIR: &str = "tests/data";
+
+/// Which of a day's inputs to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    /// The personal puzzle input, kept out of the repo.
+    Real,
+    /// One of the numbered published examples, starting at 1.
+    Example(u32),
+}
+
+impl Slot {
+    pub fn from_example(example: Option<u32>) -> Self {
+        example.map_or(Slot::Real, Slot::Example)
+    }
+}
+
+/// Maps a day and slot to the files holding its input and recorded answers.
+#[derive(Debug, Clone)]
+pub struct Resolver {
+    inputs: PathBuf,
+    answers: PathBuf,
+    examples: PathBuf,
+}
+
+impl Resolver {
+    pub fn new(inputs: impl Into<PathBuf>, answers: impl Into<PathBuf>) -> Self {
+        Self {
+            inputs: inputs.into(),
+            answers: answers.into(),
+            examples: EXAMPLES_DIR.into(),
+        }
+    }
+
+    pub fn input(&self, day: u8, slot: Slot) -> PathBuf {
+        match slot {
+            Slot::Real => self.inputs.join(format!("day{:02}.txt", day)),
+            Slot::Example(n) => self.example_dir(day).join(format!("example{}.txt", n)),
+        }
+    }
+
+    pub fn answers(&self, day: u8, slot: Slot) -> PathBuf {
+        match slot {
+            Slot::Real => self.answers.join(format!("day{:02}.txt", day)),
+            Slot::Example(n) => self.example_dir(day).join(format!("example{}.answers", n)),
+        }
+    }
+
+    fn example_dir(&self, day: u8) -> PathBuf {
+        self.examples.join(format!("day{:02}", day))
+    }
+
+    pub fn read_input(&self, day: u8, slot: Slot) -> anyhow::Result<String> {
+        util::read_input(self.input(day, slot))
+    }
+
+    /// Recorded answers for a day, treating a missing file as nothing recorded.
+    pub fn read_answers(&self, day: u8, slot: Slot) -> anyhow::Result<Answers> {
+        let file = self.answers(day, slot);
+        if !file.exists() {
+            return Ok(Answers::default());
+        }
+        Answers::read(&file)
+    }
+}
+
+impl Default for Resolver {
+    fn default() -> Self {
+        Self::new("inputs", "answers")
+    }
+}
@@ -1,4 +1,13 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
 pub mod graph;
 pub mod grid;
+pub mod inputs;
+pub mod log;
 pub mod parser;
+pub mod point;
+pub mod report;
+pub mod scaffold;
+pub mod solution;
 pub mod util;
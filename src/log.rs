@@ -0,0 +1,130 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use serde::Serialize;
+
+/// How much a message matters, from most to least important.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        f.write_str(name)
+    }
+}
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Shows messages up to and including `level`.
+pub fn set_max_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Picks a level from the number of `-v` flags, starting at warnings.
+pub fn level_from_verbosity(verbose: u8) -> Level {
+    let index = usize::from(verbose) + Level::Warn as usize;
+    Level::ALL[index.min(Level::ALL.len() - 1)]
+}
+
+/// Whether messages at `level` go anywhere; use it to skip expensive renders.
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// A message captured while a day was running.
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub level: Level,
+    pub message: String,
+}
+
+thread_local! {
+    static CAPTURED: RefCell<Option<Vec<Record>>> = const { RefCell::new(None) };
+}
+
+/// Writes a message to stderr, or records it when something is capturing. Use
+/// through the [`error!`](crate::error), [`warn!`](crate::warn),
+/// [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace)
+/// macros.
+pub fn log(level: Level, args: fmt::Arguments) {
+    if !enabled(level) {
+        return;
+    }
+    CAPTURED.with(|captured| match captured.borrow_mut().as_mut() {
+        Some(records) => records.push(Record {
+            level,
+            message: args.to_string(),
+        }),
+        None => eprintln!("[{}] {}", level, args),
+    })
+}
+
+/// Runs `f`, collecting the messages it logs instead of writing them out.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    let previous = CAPTURED.with(|captured| captured.replace(Some(Vec::new())));
+    let res = f();
+    let records = CAPTURED.with(|captured| captured.replace(previous));
+    (res, records.unwrap_or_default())
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => {
+        $crate::log::log($crate::log::Level::Error, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        $crate::log::log($crate::log::Level::Warn, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        $crate::log::log($crate::log::Level::Info, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*))
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        $crate::log::log($crate::log::Level::Trace, format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,122 @@
+use std::ops;
+
+/// A position or offset on the plane. Converts to and from the `(isize, isize)`
+/// tuples used by [`crate::grid`], keeping the same component order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point(pub isize, pub isize);
+
+impl Point {
+    pub const ZERO: Self = Self(0, 0);
+
+    pub fn new(a: isize, b: isize) -> Self {
+        Self(a, b)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        let Self(a, b) = self - other;
+        a.unsigned_abs() + b.unsigned_abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        let Self(a, b) = self - other;
+        a.unsigned_abs().max(b.unsigned_abs())
+    }
+
+    /// Divides both components by their greatest common divisor, giving the
+    /// smallest step in the same direction. The zero vector is left as is.
+    pub fn reduce(self) -> Self {
+        fn gcd(mut x: isize, mut y: isize) -> isize {
+            while y != 0 {
+                (x, y) = (y, x % y);
+            }
+            x
+        }
+
+        let divisor = gcd(self.0.abs(), self.1.abs());
+        if divisor == 0 {
+            return self;
+        }
+        Self(self.0 / divisor, self.1 / divisor)
+    }
+
+    /// Wraps each component into `0..bounds`, as on a torus.
+    pub fn wrap(self, bounds: Self) -> Self {
+        Self(self.0.rem_euclid(bounds.0), self.1.rem_euclid(bounds.1))
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((a, b): (isize, isize)) -> Self {
+        Self(a, b)
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(Point(a, b): Point) -> Self {
+        (a, b)
+    }
+}
+
+impl ops::Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl ops::AddAssign for Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::Sub for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl ops::SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl ops::Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0, -self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_test() {
+        let p = Point(2, -3);
+        assert_eq!(p + Point(1, 1), Point(3, -2));
+        assert_eq!(p - Point(1, 1), Point(1, -4));
+        assert_eq!(p * 3, Point(6, -9));
+        assert_eq!(-p, Point(-2, 3));
+        assert_eq!(p.manhattan(Point::ZERO), 5);
+        assert_eq!(p.chebyshev(Point(-1, 0)), 3);
+        assert_eq!(Point(4, -6).reduce(), Point(2, -3));
+        assert_eq!(Point::ZERO.reduce(), Point::ZERO);
+        assert_eq!(Point(-1, 12).wrap(Point(11, 7)), Point(10, 5));
+        assert_eq!(<(isize, isize)>::from(Point::from((1, 2))), (1, 2));
+    }
+}
@@ -0,0 +1,164 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+use crate::{
+    answers::Verdict,
+    log::{self, Record},
+    solution::Day,
+};
+
+/// Outcome of running one part of a day, as printed by `--format json`.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub timing: Timing,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<Verdict>,
+    pub diagnostics: Vec<Record>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct Timing {
+    pub parse_ns: u64,
+    pub part_ns: u64,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Report {
+    /// A report for a part that could not run at all, e.g. a missing input.
+    pub fn failed(day: u8, part: u8, error: &anyhow::Error) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            error: Some(format!("{:#}", error)),
+            timing: Timing::default(),
+            check: None,
+            diagnostics: Vec::new(),
+        }
+    }
+}
+
+/// Runs `f`, collecting what it logs when `capture` is set and otherwise
+/// letting it through to stderr as it happens.
+fn maybe_capture<T>(capture: bool, f: impl FnOnce() -> T) -> (T, Vec<Record>) {
+    if capture {
+        log::capture(f)
+    } else {
+        (f(), Vec::new())
+    }
+}
+
+/// Runs `f`, turning a panic into an error so one broken day is reported as
+/// failed instead of ending a run over all of them.
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".to_string());
+        Err(anyhow::anyhow!("panicked: {}", message))
+    })
+}
+
+/// Parses `input` once and runs each of `parts` on it, timing every stage.
+/// With `capture` set, log messages end up in the reports' diagnostics.
+pub fn run(day: &Day, parts: &[u8], input: &str, capture: bool) -> Vec<Report> {
+    let start = Instant::now();
+    let (parsed, parse_diagnostics) = maybe_capture(capture, || catch_panic(|| day.parse(input)));
+    let parse_ns = nanos(start.elapsed());
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return parts
+                .iter()
+                .map(|&part| Report {
+                    timing: Timing {
+                        parse_ns,
+                        part_ns: 0,
+                    },
+                    diagnostics: parse_diagnostics.clone(),
+                    ..Report::failed(day.day, part, &e)
+                })
+                .collect()
+        }
+    };
+
+    parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (res, diagnostics) =
+                maybe_capture(capture, || catch_panic(|| day.part(part, parsed.as_ref())));
+            let part_ns = nanos(start.elapsed());
+            let (answer, error) = match res {
+                Ok(answer) => (Some(answer), None),
+                Err(e) => (None, Some(format!("{:#}", e))),
+            };
+            Report {
+                day: day.day,
+                part,
+                answer,
+                error,
+                timing: Timing { parse_ns, part_ns },
+                check: None,
+                diagnostics: parse_diagnostics
+                    .iter()
+                    .cloned()
+                    .chain(diagnostics)
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+    use crate::solution::Solution;
+
+    struct Panics;
+
+    impl Solution for Panics {
+        type Input = ();
+
+        fn parse(_: &str) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> anyhow::Result<impl fmt::Display> {
+            Ok(1)
+        }
+
+        fn part2(_: &()) -> anyhow::Result<impl fmt::Display> {
+            if true {
+                panic!("out of range");
+            }
+            Ok(2)
+        }
+    }
+
+    #[test]
+    fn report_test() {
+        let reports = run(&Day::new::<Panics>(1), &[1, 2], "", true);
+        assert_eq!(reports[0].answer.as_deref(), Some("1"));
+        assert_eq!(reports[1].answer, None);
+        assert_eq!(reports[1].error.as_deref(), Some("panicked: out of range"));
+    }
+}
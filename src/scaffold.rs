@@ -0,0 +1,190 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use crate::inputs;
+
+fn module_template(day: u8) -> String {
+    format!(
+        r#"use std::fmt;
+
+use crate::solution::Solution;
+
+fn parse_input(input: &str) -> anyhow::Result<Vec<String>> {{
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}}
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {{
+        parse_input(input)
+    }}
+
+    fn part1(_input: &Self::Input) -> anyhow::Result<impl fmt::Display> {{
+        Err::<usize, _>(anyhow::anyhow!("day {day} part 1 is not solved yet"))
+    }}
+
+    fn part2(_input: &Self::Input) -> anyhow::Result<impl fmt::Display> {{
+        Err::<usize, _>(anyhow::anyhow!("day {day} part 2 is not solved yet"))
+    }}
+}}
+"#
+    )
+}
+
+fn test_template(day: u8) -> String {
+    format!("#[test]\nfn day{day:02}() {{\n    check({day});\n}}\n")
+}
+
+/// Reads the day number out of a line such as `pub mod day07;` or
+/// `Day::new::<day07::Day07>(7),`.
+fn day_in_line(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    rest.get(..2)?.parse().ok()
+}
+
+/// Inserts `new` before the first line naming a later day, or after the last
+/// line naming an earlier one. Without either it goes after the `anchor` line,
+/// or at the top.
+fn insert_ordered(text: &str, day: u8, prefix: &str, new: &str, anchor: Option<&str>) -> String {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_in_line(line, prefix).map(|d| (i, d)))
+        .collect();
+
+    let at = match days.iter().find(|(_, d)| *d > day) {
+        Some((i, _)) => *i,
+        None => match (days.last(), anchor) {
+            (Some((i, _)), _) => i + 1,
+            (None, Some(anchor)) => lines
+                .iter()
+                .position(|line| line.starts_with(anchor))
+                .map_or(lines.len(), |i| i + 1),
+            (None, None) => 0,
+        },
+    };
+
+    lines.insert(at, new);
+    lines.join("\n") + "\n"
+}
+
+fn register(days_mod: &str, day: u8) -> String {
+    let res = insert_ordered(
+        days_mod,
+        day,
+        "pub mod day",
+        &format!("pub mod day{:02};", day),
+        None,
+    );
+    insert_ordered(
+        &res,
+        day,
+        "Day::new::<day",
+        &format!("    Day::new::<day{day:02}::Day{day:02}>({day}),"),
+        Some("pub const DAYS"),
+    )
+}
+
+fn add_test(examples: &str, day: u8) -> String {
+    let lines: Vec<&str> = examples.lines().collect();
+    // each test is a `#[test]` line followed by `fn dayNN()`
+    let later = lines
+        .windows(2)
+        .position(|w| w[0] == "#[test]" && day_in_line(w[1], "fn day").is_some_and(|d| d > day));
+    match later {
+        Some(at) => {
+            let (before, after) = lines.split_at(at);
+            format!(
+                "{}\n{}\n{}\n",
+                before.join("\n"),
+                test_template(day),
+                after.join("\n")
+            )
+        }
+        None => format!("{}\n{}", examples, test_template(day)),
+    }
+}
+
+/// Generates a new day under `root`: the module, its registration in
+/// `src/days/mod.rs`, an empty example fixture and a test that fails until
+/// the example's answers are filled in. Returns the files it touched.
+pub fn new_day(root: &Path, day: u8) -> anyhow::Result<Vec<PathBuf>> {
+    anyhow::ensure!((1..=25).contains(&day), "day {} is not in 1..=25", day);
+
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    let days_mod = root.join("src/days/mod.rs");
+    let data = root
+        .join(inputs::EXAMPLES_DIR)
+        .join(format!("day{:02}", day));
+    let examples = root.join("tests/examples.rs");
+
+    let days_text = std::fs::read_to_string(&days_mod)
+        .with_context(|| format!("could not read {}", days_mod.display()))?;
+    let examples_text = std::fs::read_to_string(&examples)
+        .with_context(|| format!("could not read {}", examples.display()))?;
+
+    for path in [&module, &data] {
+        anyhow::ensure!(!path.exists(), "{} already exists", path.display());
+    }
+    anyhow::ensure!(
+        days_text
+            .lines()
+            .all(|line| day_in_line(line, "pub mod day") != Some(day)),
+        "day {} is already registered",
+        day
+    );
+
+    std::fs::write(&module, module_template(day))?;
+    std::fs::write(&days_mod, register(&days_text, day))?;
+    std::fs::create_dir_all(&data)?;
+    let input = data.join("example1.txt");
+    let answers = data.join("example1.answers");
+    std::fs::write(&input, "")?;
+    std::fs::write(&answers, "-\n-\n")?;
+    std::fs::write(&examples, add_test(&examples_text, day))?;
+
+    Ok(vec![module, days_mod, input, answers, examples])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_test() {
+        let days_mod = "\
+pub mod day01;
+pub mod day03;
+
+use crate::solution::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day03::Day03>(3),
+];
+";
+        let expected = "\
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+use crate::solution::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+];
+";
+        assert_eq!(register(days_mod, 2), expected);
+
+        let empty = "use crate::solution::Day;\n\npub const DAYS: &[Day] = &[\n];\n";
+        let expected = "pub mod day04;\nuse crate::solution::Day;\n\npub const DAYS: &[Day] = &[\n    Day::new::<day04::Day04>(4),\n];\n";
+        assert_eq!(register(empty, 4), expected);
+    }
+}
@@ -0,0 +1,92 @@
+use std::{any::Any, fmt};
+
+/// A day's puzzle split into stages: the input is parsed once and shared by
+/// both parts.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> anyhow::Result<impl fmt::Display>;
+
+    fn part2(input: &Self::Input) -> anyhow::Result<impl fmt::Display>;
+}
+
+/// Type erased handle to a [`Solution`] so that days with different inputs can
+/// live in the same registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&str) -> anyhow::Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> anyhow::Result<String>,
+    part2: fn(&dyn Any) -> anyhow::Result<String>,
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
+    }
+}
+
+fn parse_erased<S: Solution>(input: &str) -> anyhow::Result<Box<dyn Any>>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> anyhow::Result<&S::Input>
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .ok_or_else(|| anyhow::anyhow!("input was not parsed by this day"))
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any) -> anyhow::Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part1(downcast::<S>(input)?)?.to_string())
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any) -> anyhow::Result<String>
+where
+    S::Input: 'static,
+{
+    Ok(S::part2(downcast::<S>(input)?)?.to_string())
+}
+
+impl Day {
+    pub const fn new<S: Solution>(day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Self {
+            day,
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: part2_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> anyhow::Result<Box<dyn Any>> {
+        (self.parse)(input)
+    }
+
+    /// Runs a part on an input previously returned by [`Day::parse`].
+    pub fn part(&self, part: u8, input: &dyn Any) -> anyhow::Result<String> {
+        match part {
+            1 => (self.part1)(input),
+            2 => (self.part2)(input),
+            _ => Err(anyhow::anyhow!("day {} has no part {}", self.day, part)),
+        }
+    }
+
+    /// Parses and solves a part from the puzzle text itself rather than a path.
+    pub fn run(&self, part: u8, input: &str) -> anyhow::Result<String> {
+        let input = self.parse(input)?;
+        self.part(part, input.as_ref())
+    }
+}
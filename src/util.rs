@@ -1,14 +1,22 @@
+use anyhow::Context;
 use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, Lines},
+    io::{self, BufRead, BufReader, Lines, Read},
+    path::Path,
 };
 
-pub fn read_file_lines(
-    filename: &str,
-) -> Result<ExtractErrorIterator<String, io::Error, Lines<BufReader<File>>>, io::Error> {
-    let file = std::fs::File::open(filename)?;
-    let bufreader = std::io::BufReader::new(file);
-    Ok(ExtractErrorIterator::new(bufreader.lines()))
+/// Reads a whole puzzle input into memory, treating `-` as stdin.
+pub fn read_input(filename: impl AsRef<Path>) -> anyhow::Result<String> {
+    let filename = filename.as_ref();
+    let mut input = String::new();
+    if filename == Path::new("-") {
+        io::stdin()
+            .read_to_string(&mut input)
+            .context("could not read stdin")?;
+    } else {
+        input = std::fs::read_to_string(filename)
+            .with_context(|| format!("could not read {}", filename.display()))?;
+    }
+    Ok(input)
 }
 
 #[allow(clippy::type_complexity)]
@@ -0,0 +1,169 @@
+//! Runs every day against the published examples in `tests/data/dayNN/`.
+//! Each `exampleN.txt` sits next to an `exampleN.answers` file holding the
+//! expected answer for each part.
+
+use std::path::Path;
+
+use aoc24::{answers::Answers, days};
+
+fn check(day: u8) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data")
+        .join(format!("day{:02}", day));
+    let solution = days::get(day).expect("day is not registered");
+
+    let mut checked = 0;
+    for example in 1.. {
+        let path = dir.join(format!("example{}.txt", example));
+        if !path.exists() {
+            break;
+        }
+        let input = std::fs::read_to_string(&path).unwrap();
+        let answers = Answers::read(path.with_extension("answers")).unwrap();
+        let parsed = solution
+            .parse(&input)
+            .unwrap_or_else(|e| panic!("day {} example {}: {:#}", day, example, e));
+
+        for part in [1, 2] {
+            let Some(expected) = answers.part(part) else {
+                continue;
+            };
+            let answer = solution.part(part, parsed.as_ref()).unwrap_or_else(|e| {
+                panic!("day {} example {} part {}: {:#}", day, example, part, e)
+            });
+            assert_eq!(
+                answer, expected,
+                "day {} example {} part {}",
+                day, example, part
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "day {} has no example answers", day);
+}
+
+#[test]
+fn day01() {
+    check(1);
+}
+
+#[test]
+fn day02() {
+    check(2);
+}
+
+#[test]
+fn day03() {
+    check(3);
+}
+
+#[test]
+fn day04() {
+    check(4);
+}
+
+#[test]
+fn day05() {
+    check(5);
+}
+
+#[test]
+fn day06() {
+    check(6);
+}
+
+#[test]
+fn day07() {
+    check(7);
+}
+
+#[test]
+fn day08() {
+    check(8);
+}
+
+#[test]
+fn day09() {
+    check(9);
+}
+
+#[test]
+fn day10() {
+    check(10);
+}
+
+#[test]
+fn day11() {
+    check(11);
+}
+
+#[test]
+fn day12() {
+    check(12);
+}
+
+#[test]
+fn day13() {
+    check(13);
+}
+
+#[test]
+fn day14() {
+    check(14);
+}
+
+#[test]
+fn day15() {
+    check(15);
+}
+
+#[test]
+fn day16() {
+    check(16);
+}
+
+#[test]
+fn day17() {
+    check(17);
+}
+
+#[test]
+fn day18() {
+    check(18);
+}
+
+#[test]
+fn day19() {
+    check(19);
+}
+
+#[test]
+fn day20() {
+    check(20);
+}
+
+#[test]
+fn day21() {
+    check(21);
+}
+
+#[test]
+fn day22() {
+    check(22);
+}
+
+#[test]
+fn day23() {
+    check(23);
+}
+
+#[test]
+fn day24() {
+    check(24);
+}
+
+#[test]
+fn day25() {
+    check(25);
+}